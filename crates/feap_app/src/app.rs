@@ -1,15 +1,18 @@
 use crate::{
     Plugin, Plugins, SubApp, SubApps,
     main_schedule::{Main, MainSchedulePlugin},
-    plugin::{PlaceholderPlugin, PluginsState},
+    plugin::{PlaceholderPlugin, PluginsState, RequiredPlugin},
+};
+use core::{
+    any::TypeId,
+    panic::{AssertUnwindSafe, Location},
 };
-use core::panic::AssertUnwindSafe;
 use feap_core::collections::HashMap;
 use feap_ecs::{
-    schedule::{IntoScheduleConfigs, Schedule, ScheduleLabel, InternedSystemSet},
+    schedule::{IntoScheduleConfigs, Schedule, ScheduleBuildError, ScheduleLabel, InternedSystemSet},
     system::ScheduleSystem,
     resource::Resource,
-    world::FromWorld,
+    world::{FromWorld, World},
 };
 
 #[cfg(feature = "trace")]
@@ -18,10 +21,25 @@ use tracing::info_span;
 #[cfg(feature = "std")]
 use std::panic::{catch_unwind, resume_unwind};
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum AppError {
     #[error("duplicate plugin {plugin_name:?}")]
     DuplicatePlugin { plugin_name: String },
+    #[error("plugin {plugin_name:?} depends on {dependency:?}, which has not been added")]
+    MissingPluginDependency {
+        plugin_name: String,
+        dependency: String,
+    },
+    #[error("plugin dependencies contain a cycle between {plugin_names:?}")]
+    PluginDependencyCycle { plugin_names: Vec<String> },
+    #[error("plugin {plugin_name:?} is required at {location}, but has not been added")]
+    MissingRequiredPlugin {
+        plugin_name: &'static str,
+        location: &'static Location<'static>,
+    },
 }
 
 /// [`App`] is the primary API for writing user applications. It automates the setup of a
@@ -88,6 +106,35 @@ impl App {
         runner(app);
     }
 
+    /// Returns a reference to the main [`SubApp`]'s [`World`]
+    pub fn world(&self) -> &World {
+        self.main().world()
+    }
+
+    /// Returns a mutable reference to the main [`SubApp`]'s [`World`]
+    pub fn world_mut(&mut self) -> &mut World {
+        self.main_mut().world_mut()
+    }
+
+    /// Returns `true` if a plugin of type `T` has been added to the app
+    pub fn is_plugin_added<T: Plugin>(&self) -> bool {
+        self.main().is_plugin_added::<T>()
+    }
+
+    /// Requires a plugin of type `P` to be added to the app by the time [`App::finish`] runs
+    ///
+    /// Unlike [`Plugin::dependencies`], the plugin may be added after the requirement is
+    /// registered. [`App::finish`] panics if it is still missing
+    #[track_caller]
+    pub fn register_required_plugin<P: Plugin>(&mut self) -> &mut Self {
+        self.main_mut().required_plugins.push(RequiredPlugin {
+            type_id: TypeId::of::<P>(),
+            name: core::any::type_name::<P>(),
+            location: Location::caller(),
+        });
+        self
+    }
+
     /// Returns `true` if any of the sub-apps are building plugins
     pub(crate) fn is_building_plugins(&self) -> bool {
         self.sub_apps.iter().any(SubApp::is_building_plugins)
@@ -128,6 +175,9 @@ impl App {
     ///
     /// [`Plugin`]s can be grouped into a set by using a [`PluginGroup`].
     ///
+    /// Plugins added together are built in an order satisfying their [`Plugin::dependencies`]
+    /// and [`Plugin::before`] hints. Panics if those hints form a cycle or name a missing dependency.
+    ///
     #[track_caller]
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
         if matches!(
@@ -142,6 +192,87 @@ impl App {
         self
     }
 
+    /// Adds a batch of plugins, building them in an order which satisfies their
+    /// [`Plugin::dependencies`] and [`Plugin::before`] hints
+    ///
+    /// Plugins without ordering constraints keep their insertion order
+    pub(crate) fn add_boxed_plugins(
+        &mut self,
+        plugins: Vec<Box<dyn Plugin>>,
+    ) -> Result<&mut Self, AppError> {
+        for plugin in self.sort_plugins(plugins)? {
+            self.add_boxed_plugin(plugin)?;
+        }
+        Ok(self)
+    }
+
+    /// Topologically sorts `plugins` by their declared ordering constraints
+    fn sort_plugins(
+        &self,
+        plugins: Vec<Box<dyn Plugin>>,
+    ) -> Result<Vec<Box<dyn Plugin>>, AppError> {
+        let position =
+            |name: &str| -> Option<usize> { plugins.iter().position(|p| p.name() == name) };
+
+        // successors[i] holds the plugins that must be built after plugin `i`
+        let mut successors = vec![Vec::new(); plugins.len()];
+        let mut in_degree = vec![0usize; plugins.len()];
+        for (index, plugin) in plugins.iter().enumerate() {
+            for dependency in plugin.dependencies() {
+                if let Some(dependency_index) = position(dependency) {
+                    successors[dependency_index].push(index);
+                    in_degree[index] += 1;
+                } else if !self.main().plugin_names.contains(dependency) {
+                    return Err(AppError::MissingPluginDependency {
+                        plugin_name: plugin.name().to_string(),
+                        dependency: dependency.to_string(),
+                    });
+                }
+            }
+            for successor in plugin.before() {
+                if let Some(successor_index) = position(successor) {
+                    successors[index].push(successor_index);
+                    in_degree[successor_index] += 1;
+                } else if self.main().plugin_names.contains(successor) {
+                    log::warn!(
+                        "plugin {} should be built before {successor}, which was already added",
+                        plugin.name()
+                    );
+                }
+            }
+        }
+
+        // Kahn's algorithm, always picking the earliest inserted plugin that is ready
+        let mut order = Vec::with_capacity(plugins.len());
+        let mut done = vec![false; plugins.len()];
+        while let Some(index) =
+            (0..plugins.len()).find(|&index| !done[index] && in_degree[index] == 0)
+        {
+            done[index] = true;
+            order.push(index);
+            for &successor in &successors[index] {
+                in_degree[successor] -= 1;
+            }
+        }
+
+        if order.len() < plugins.len() {
+            return Err(AppError::PluginDependencyCycle {
+                plugin_names: plugins
+                    .iter()
+                    .zip(done)
+                    .filter(|(_, done)| !done)
+                    .map(|(plugin, _)| plugin.name().to_string())
+                    .collect(),
+            });
+        }
+
+        let mut plugins: Vec<_> = plugins.into_iter().map(Some).collect();
+        Ok(order
+            .into_iter()
+            .map(|index| plugins[index].take().unwrap())
+            .collect())
+    }
+
     pub(crate) fn add_boxed_plugin(
         &mut self,
         plugin: Box<dyn Plugin>,
@@ -166,10 +297,14 @@ impl App {
 
         let f = AssertUnwindSafe(|| plugin.build(self));
 
-        #[cfg(feature = "std")]
-        let result = catch_unwind(f);
-        #[cfg(not(feature = "std"))]
-        f();
+        feap_core::cfg::switch! {
+            #[cfg(feature = "std")] => {
+                let result = catch_unwind(f);
+            }
+            _ => {
+                f();
+            }
+        }
 
         self.main_mut()
             .plugin_names
@@ -193,6 +328,7 @@ impl App {
     }
 
     /// Adds one or more systems to the given schedule in this app's  [`Schedules`]
+    #[track_caller]
     pub fn add_systems<M>(
         &mut self,
         schedule: impl ScheduleLabel,
@@ -213,6 +349,13 @@ impl App {
         self
     }
 
+    /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type
+    #[track_caller]
+    pub fn insert_resource<R: Resource>(&mut self, resource: R) -> &mut Self {
+        self.main_mut().insert_resource(resource);
+        self
+    }
+
     /// Inserts the [`Resource`], initialized with its default value, into the app,
     /// if there is no existing instance of `R`
     pub fn init_resource<R: Resource + FromWorld>(&mut self) -> &mut Self {
@@ -222,9 +365,18 @@ impl App {
 
     /// Runs [`Plugin::finish`] for each plugin. This is usually called by the event loop once all
     /// plugins are ready
+    ///
+    /// Panics if a plugin registered with [`App::register_required_plugin`] has not been added
     pub fn finish(&mut self) {
         #[cfg(feature = "trace")]
         let _finish_span = info_span!("plugin finish").entered();
+        if let Some(required) = self.main().missing_required_plugin() {
+            let error = AppError::MissingRequiredPlugin {
+                plugin_name: required.name,
+                location: required.location,
+            };
+            panic!("Error finishing plugins: {error}");
+        }
         // Plugins installed to main should see all sub-apps
         // do hokey pokey with a boxed zst plugin (doesn't allocate)
         let mut hokeypokey: Box<dyn Plugin> = Box::new(HokeyPokey);
@@ -260,6 +412,17 @@ impl App {
         self.sub_apps.iter_mut().skip(1).for_each(SubApp::cleanup);
     }
     
+    /// Builds and initializes the schedules of all sub-apps (starting with the "main" app),
+    /// so the graph build cost is paid up front rather than on the first [`App::update`]
+    ///
+    /// Build warnings are reported through the error handler of each sub-app's world.
+    /// Returns the first build error encountered
+    pub fn initialize_schedules(&mut self) -> Result<(), ScheduleBuildError> {
+        self.sub_apps
+            .iter_mut()
+            .try_for_each(SubApp::initialize_schedules)
+    }
+
     /// Runs the default schedules of all sub-apps (starting with the "main" app) once
     pub fn update(&mut self) {
         if self.is_building_plugins() {
@@ -0,0 +1,150 @@
+//! Tests for ordering and requiring the plugins added to an [`App`]
+
+use super::{App, AppError};
+use crate::{Plugin, plugin::PluginsState};
+use feap_ecs::resource::Resource;
+
+/// Records the order in which plugins were built
+#[derive(Resource, Default)]
+struct BuildOrder(Vec<&'static str>);
+
+/// A plugin with a name and ordering constraints
+struct Named {
+    name: &'static str,
+    dependencies: Vec<&'static str>,
+    before: Vec<&'static str>,
+}
+
+fn plugin(name: &'static str) -> Named {
+    Named {
+        name,
+        dependencies: Vec::new(),
+        before: Vec::new(),
+    }
+}
+
+impl Named {
+    fn after(mut self, dependency: &'static str) -> Self {
+        self.dependencies.push(dependency);
+        self
+    }
+
+    fn before(mut self, successor: &'static str) -> Self {
+        self.before.push(successor);
+        self
+    }
+}
+
+impl Plugin for Named {
+    fn build(&self, app: &mut App) {
+        app.world_mut()
+            .get_resource_or_init::<BuildOrder>()
+            .0
+            .push(self.name);
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn dependencies(&self) -> Vec<&str> {
+        self.dependencies.clone()
+    }
+
+    fn before(&self) -> Vec<&str> {
+        self.before.clone()
+    }
+}
+
+/// Adds `plugins` in one batch and returns the order they were built in
+fn build(app: &mut App, plugins: Vec<Named>) -> Result<Vec<&'static str>, AppError> {
+    let plugins = plugins
+        .into_iter()
+        .map(|plugin| Box::new(plugin) as Box<dyn Plugin>)
+        .collect();
+    app.add_boxed_plugins(plugins)?;
+    Ok(app.world_mut().get_resource_or_init::<BuildOrder>().0.clone())
+}
+
+#[test]
+fn unconstrained_plugins_keep_insertion_order() {
+    let order = build(&mut App::empty(), vec![plugin("c"), plugin("a"), plugin("b")]);
+    assert_eq!(order.unwrap(), ["c", "a", "b"]);
+}
+
+#[test]
+fn dependencies_are_built_first() {
+    let plugins = vec![plugin("a").after("c"), plugin("b"), plugin("c").after("b")];
+    assert_eq!(build(&mut App::empty(), plugins).unwrap(), ["b", "c", "a"]);
+}
+
+#[test]
+fn before_hints_reorder_successors() {
+    let plugins = vec![plugin("a"), plugin("b").before("a"), plugin("c")];
+    assert_eq!(build(&mut App::empty(), plugins).unwrap(), ["b", "a", "c"]);
+}
+
+#[test]
+fn before_hints_on_missing_plugins_are_ignored() {
+    let plugins = vec![plugin("a").before("missing"), plugin("b")];
+    assert_eq!(build(&mut App::empty(), plugins).unwrap(), ["a", "b"]);
+}
+
+#[test]
+fn dependencies_may_have_been_added_earlier() {
+    let mut app = App::empty();
+    build(&mut app, vec![plugin("a")]).unwrap();
+    let order = build(&mut app, vec![plugin("b").after("a")]);
+    assert_eq!(order.unwrap(), ["a", "b"]);
+}
+
+#[test]
+fn missing_dependencies_are_reported() {
+    let mut app = App::empty();
+    let plugins = vec![plugin("a"), plugin("b").after("missing")];
+    match build(&mut app, plugins) {
+        Err(AppError::MissingPluginDependency {
+            plugin_name,
+            dependency,
+        }) => {
+            assert_eq!(plugin_name, "b");
+            assert_eq!(dependency, "missing");
+        }
+        result => panic!("expected a missing dependency, got {result:?}"),
+    }
+    // Nothing is built when the batch cannot be ordered
+    assert!(app.world().get_resource::<BuildOrder>().is_none());
+}
+
+#[test]
+fn cycles_are_reported_with_their_plugins() {
+    let mut app = App::empty();
+    let plugins = vec![
+        plugin("free"),
+        plugin("a").after("c"),
+        plugin("b").after("a"),
+        plugin("c").after("b"),
+    ];
+    match build(&mut app, plugins) {
+        Err(AppError::PluginDependencyCycle { plugin_names }) => {
+            assert_eq!(plugin_names, ["a", "b", "c"]);
+        }
+        result => panic!("expected a dependency cycle, got {result:?}"),
+    }
+    assert!(app.world().get_resource::<BuildOrder>().is_none());
+}
+
+#[test]
+fn required_plugins_may_be_added_after_the_requirement() {
+    let mut app = App::empty();
+    app.register_required_plugin::<Named>();
+    build(&mut app, vec![plugin("a")]).unwrap();
+    app.finish();
+    assert_eq!(app.plugins_state(), PluginsState::Finished);
+}
+
+#[test]
+#[should_panic(expected = "plugin \"feap_app::app::tests::Named\" is required at")]
+fn missing_required_plugins_panic_on_finish() {
+    App::empty().register_required_plugin::<Named>().finish();
+}
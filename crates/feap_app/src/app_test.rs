@@ -0,0 +1,160 @@
+use crate::{App, Plugins};
+use core::{any::type_name, fmt::Debug};
+use feap_ecs::{
+    message::{Message, Messages},
+    resource::Resource,
+    schedule::{IntoScheduleConfigs, ScheduleLabel},
+    system::ScheduleSystem,
+    world::World,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A headless [`App`] wrapper for integration tests
+///
+/// The app is built with the default main schedule and is never handed to a runner.
+/// Plugins are finished and cleaned up before the first update, after which every call to
+/// [`AppTest::update`] runs the main schedule exactly once, so tests are deterministic.
+///
+/// ```ignore
+/// AppTest::new()
+///     .add_plugins(ScorePlugin)
+///     .run(3)
+///     .assert_resource_eq(&Score(3));
+/// ```
+pub struct AppTest {
+    app: App,
+    finished: bool,
+}
+
+impl Default for AppTest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AppTest {
+    /// Creates a harness around a new [`App`] with the default schedule structure
+    pub fn new() -> Self {
+        Self {
+            app: App::new(),
+            finished: false,
+        }
+    }
+
+    /// Returns a reference to the wrapped [`App`]
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns a mutable reference to the wrapped [`App`]
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Returns a reference to the main [`World`]
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    /// Returns a mutable reference to the main [`World`]
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// See [`App::add_plugins`]
+    #[track_caller]
+    pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
+        self.app.add_plugins(plugins);
+        self
+    }
+
+    /// See [`App::add_systems`]
+    #[track_caller]
+    pub fn add_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self {
+        self.app.add_systems(schedule, systems);
+        self
+    }
+
+    /// See [`App::insert_resource`]
+    #[track_caller]
+    pub fn insert_resource<R: Resource>(&mut self, resource: R) -> &mut Self {
+        self.app.insert_resource(resource);
+        self
+    }
+
+    /// Runs a single update, finishing and cleaning up the plugins first if needed
+    pub fn update(&mut self) -> &mut Self {
+        if !self.finished {
+            self.app.finish();
+            self.app.cleanup();
+            self.finished = true;
+        }
+        self.app.update();
+        self
+    }
+
+    /// Runs `updates` updates in a row
+    pub fn run(&mut self, updates: usize) -> &mut Self {
+        for _ in 0..updates {
+            self.update();
+        }
+        self
+    }
+
+    /// Returns the resource of type `R`
+    ///
+    /// Panics if the resource does not exist
+    #[track_caller]
+    pub fn resource<R: Resource>(&self) -> &R {
+        self.world()
+            .get_resource::<R>()
+            .unwrap_or_else(|| panic!("resource {} does not exist in the app", type_name::<R>()))
+    }
+
+    /// Asserts that the resource of type `R` equals `expected`
+    #[track_caller]
+    pub fn assert_resource_eq<R: Resource + PartialEq + Debug>(&self, expected: &R) -> &Self {
+        assert_eq!(self.resource::<R>(), expected);
+        self
+    }
+
+    /// Asserts that the resource of type `R` satisfies `predicate`
+    #[track_caller]
+    pub fn assert_resource<R: Resource>(&self, predicate: impl FnOnce(&R) -> bool) -> &Self {
+        assert!(
+            predicate(self.resource::<R>()),
+            "resource {} does not satisfy the predicate",
+            type_name::<R>()
+        );
+        self
+    }
+
+    /// Returns the number of messages of type `E` buffered in [`Messages<E>`]
+    ///
+    /// The app does not call [`Messages::update`] between updates yet, so this counts every
+    /// message sent since the buffers were last updated by hand, or since startup.
+    /// Returns `0` if no [`Messages<E>`] resource exists
+    pub fn message_count<E: Message>(&self) -> usize {
+        self.world()
+            .get_resource::<Messages<E>>()
+            .map_or(0, Messages::len)
+    }
+
+    /// Asserts that exactly `expected` messages of type `E` are buffered
+    #[track_caller]
+    pub fn assert_message_count<E: Message>(&self, expected: usize) -> &Self {
+        assert_eq!(
+            self.message_count::<E>(),
+            expected,
+            "unexpected number of {} messages",
+            type_name::<E>()
+        );
+        self
+    }
+}
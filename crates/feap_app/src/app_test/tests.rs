@@ -0,0 +1,59 @@
+//! Tests for the [`AppTest`] harness
+
+use super::AppTest;
+use crate::main_schedule::Update;
+use feap_ecs::{
+    message::{Message, Messages},
+    resource::Resource,
+    world::World,
+};
+
+#[derive(Resource, Default, Debug, PartialEq)]
+struct Score(u32);
+
+#[derive(Message)]
+struct Scored;
+
+fn score(world: &mut World) {
+    world.resource_mut::<Score>().0 += 1;
+    world.resource_mut::<Messages<Scored>>().send(Scored);
+}
+
+fn scoring_app() -> AppTest {
+    let mut app = AppTest::new();
+    app.insert_resource(Score::default())
+        .insert_resource(Messages::<Scored>::default())
+        .add_systems(Update, score);
+    app
+}
+
+#[test]
+fn run_updates_the_app_once_per_update() {
+    let mut app = scoring_app();
+    app.assert_resource_eq(&Score(0));
+    app.run(3).assert_resource_eq(&Score(3));
+    app.update().assert_resource_eq(&Score(4));
+    app.assert_resource(|score: &Score| score.0 % 2 == 0);
+}
+
+#[test]
+fn messages_are_counted_until_the_buffers_are_updated() {
+    let mut app = scoring_app();
+    app.assert_message_count::<Scored>(0);
+    app.run(3).assert_message_count::<Scored>(3);
+
+    app.world_mut().resource_mut::<Messages<Scored>>().update();
+    app.world_mut().resource_mut::<Messages<Scored>>().update();
+    app.assert_message_count::<Scored>(0);
+}
+
+#[test]
+#[should_panic(expected = "unexpected number of")]
+fn wrong_message_counts_panic() {
+    scoring_app().update().assert_message_count::<Scored>(2);
+}
+
+#[test]
+fn missing_messages_count_as_zero() {
+    AppTest::new().update().assert_message_count::<Scored>(0);
+}
@@ -0,0 +1,96 @@
+use crate::{App, Plugin, main_schedule::First};
+use core::marker::PhantomData;
+use feap_ecs::{resource::Resource, world::World};
+
+#[cfg(test)]
+mod tests;
+
+/// A resource holding a value that is only valid for the current frame
+///
+/// The cache is emptied at the start of every frame, in the [`First`] schedule, once a
+/// [`FrameCachePlugin<T>`] has been added. This lets systems share per-frame computed data
+/// without writing their own cleanup systems.
+///
+/// Clearing only flags the resource as changed when it actually held a value, so change
+/// detection on a `FrameCache<T>` reports real updates rather than every frame.
+#[derive(Resource, Debug)]
+pub struct FrameCache<T: Send + Sync + 'static> {
+    value: Option<T>,
+}
+
+impl<T: Send + Sync + 'static> Default for FrameCache<T> {
+    fn default() -> Self {
+        Self { value: None }
+    }
+}
+
+impl<T: Send + Sync + 'static> FrameCache<T> {
+    /// Returns the value cached for this frame, if any
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value cached for this frame, if any
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.value.as_mut()
+    }
+
+    /// Returns the value cached for this frame, computing it with `f` if it is not present yet
+    #[inline]
+    pub fn get_or_insert_with(&mut self, f: impl FnOnce() -> T) -> &mut T {
+        self.value.get_or_insert_with(f)
+    }
+
+    /// Caches `value` for the rest of this frame, returning the previous value if any
+    #[inline]
+    pub fn insert(&mut self, value: T) -> Option<T> {
+        self.value.replace(value)
+    }
+
+    /// Removes the cached value and returns it, if present
+    #[inline]
+    pub fn take(&mut self) -> Option<T> {
+        self.value.take()
+    }
+
+    /// Returns `true` if nothing has been cached this frame
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Drops the cached value
+    #[inline]
+    pub fn clear(&mut self) {
+        self.value = None;
+    }
+}
+
+/// Empties the [`FrameCache<T>`] resource, if it exists and holds a value
+pub fn clear_frame_cache<T: Send + Sync + 'static>(world: &mut World) {
+    let Some(mut cache) = world.get_resource_mut::<FrameCache<T>>() else {
+        return;
+    };
+    // Only go through `DerefMut` when there is something to clear, to keep change detection accurate
+    if !cache.is_empty() {
+        cache.clear();
+    }
+}
+
+/// Initializes the [`FrameCache<T>`] resource and clears it at the start of every frame
+pub struct FrameCachePlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for FrameCachePlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for FrameCachePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FrameCache<T>>()
+            .add_systems(First, clear_frame_cache::<T>);
+    }
+}
@@ -0,0 +1,36 @@
+//! Tests for the [`FrameCachePlugin`]
+
+use super::{FrameCache, FrameCachePlugin};
+use crate::{AppTest, main_schedule::Update};
+use feap_ecs::world::World;
+
+/// Caches a value for this frame, checking that the previous frame's value is gone
+fn cache_frame(world: &mut World) {
+    let previous = world.resource_mut::<FrameCache<u32>>().insert(1);
+    assert_eq!(
+        previous, None,
+        "the cache was not cleared since the last update"
+    );
+}
+
+#[test]
+fn cache_is_cleared_at_the_start_of_each_update() {
+    let mut app = AppTest::new();
+    app.add_plugins(FrameCachePlugin::<u32>::default());
+    app.assert_resource(FrameCache::<u32>::is_empty);
+
+    app.world_mut().resource_mut::<FrameCache<u32>>().insert(7);
+    app.update().assert_resource(FrameCache::<u32>::is_empty);
+}
+
+#[test]
+fn systems_only_see_values_cached_this_frame() {
+    let mut app = AppTest::new();
+    app.add_plugins(FrameCachePlugin::<u32>::default())
+        .add_systems(Update, cache_frame);
+
+    for _ in 0..3 {
+        app.update()
+            .assert_resource(|cache: &FrameCache<u32>| cache.get() == Some(&1));
+    }
+}
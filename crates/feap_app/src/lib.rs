@@ -1,9 +1,15 @@
 mod app;
+mod app_test;
+mod frame_cache;
 mod main_schedule;
 mod plugin;
 mod plugin_default;
 mod sub_app;
+mod world_stats;
 
 pub use app::App;
+pub use app_test::AppTest;
+pub use frame_cache::{FrameCache, FrameCachePlugin, clear_frame_cache};
 pub use plugin::{Plugin, Plugins};
 pub use sub_app::{SubApp, SubApps};
+pub use world_stats::{WorldStatsPlugin, update_world_stats};
@@ -1,8 +1,9 @@
 use crate::Plugin;
 use feap_ecs::{
     change_detection::Mut,
+    phase_sets,
     resource::Resource,
-    schedule::{ExecutorKind, InternedScheduleLabel, Schedule, ScheduleLabel, SystemSet},
+    schedule::{ExecutorKind, InternedScheduleLabel, Schedule, ScheduleLabel},
     system::Local,
     world::World,
 };
@@ -110,19 +111,20 @@ impl FixedMain {
     }
 }
 
-/// Set enum for the systems that want to run inside [`RunFixedMainLoop`]
-/// but before or after the fixed update logic. Systems in this set
-/// will run exactly once per frame, regardless of the number of fixed updates.
-/// They will also run under a variable timestep.
-///
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone, SystemSet)]
-pub enum RunFixedMainLoopSystems {
-    /// Runs before the fixed update logic
-    BeforeFixedMainLoop,
-    /// Contains the fixed update logic
-    FixedMainLoop,
-    /// Runs after the fixed update logic
-    AfterFixedMainLoop,
+phase_sets! {
+    /// Set enum for the systems that want to run inside [`RunFixedMainLoop`]
+    /// but before or after the fixed update logic. Systems in this set
+    /// will run exactly once per frame, regardless of the number of fixed updates.
+    /// They will also run under a variable timestep.
+    ///
+    pub enum RunFixedMainLoopSystems {
+        /// Runs before the fixed update logic
+        BeforeFixedMainLoop,
+        /// Contains the fixed update logic
+        FixedMainLoop,
+        /// Runs after the fixed update logic
+        AfterFixedMainLoop,
+    }
 }
 
 /// Runs first in the [`FixedMain`] schedule
@@ -210,16 +212,7 @@ impl Plugin for MainSchedulePlugin {
             .add_schedule(fixed_main_loop_schedule)
             .init_resource::<MainScheduleOrder>()
             .init_resource::<FixedMainScheduleOrder>()
-            .add_systems(Main, Main::run_main);
-        // .add_systems(FixedMain, FixedMain::run_fixed_main)
-        // .configure_sets(
-        //     RunFixedMainLoop,
-        //     (
-        //         RunFixedMainLoopSystems::BeforeFixedMainLoop,
-        //         RunFixedMainLoopSystems::FixedMainLoop,
-        //         RunFixedMainLoopSystems::AfterFixedMainLoop,
-        //     )
-        //         .chain(),
-        // );
+            .add_systems(Main, Main::run_main)
+            .configure_sets(RunFixedMainLoop, RunFixedMainLoopSystems::configs());
     }
 }
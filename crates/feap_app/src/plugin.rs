@@ -1,5 +1,8 @@
 use crate::App;
-use core::any::Any;
+use core::{
+    any::{Any, TypeId},
+    panic::Location,
+};
 use downcast_rs::Downcast;
 
 /// A collection of feap app logic and configuration
@@ -19,6 +22,10 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     }
     
     /// Finish adding this plugin to the [`App`], once all plugins registered are ready
+    ///
+    /// Runs after every plugin has been built, in build order, so plugins can set up the world
+    /// using what their dependencies registered. Requirements registered with
+    /// [`App::register_required_plugin`] are checked before any plugin finishes
     fn finish(&self, _app: &mut App) {}
     
     /// Runs after all plugins are built and finished, but before the app schedule is executed
@@ -35,6 +42,20 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// Names of the plugins that must be built before this one
+    ///
+    /// Dependencies are either added in the same [`App::add_plugins`] call, in which case the
+    /// builds are reordered accordingly, or must have been added beforehand
+    fn dependencies(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Names of the plugins that should be built after this one, if they are added
+    /// in the same [`App::add_plugins`] call
+    fn before(&self) -> Vec<&str> {
+        Vec::new()
+    }
 }
 
 /// Plugins state in the application
@@ -50,6 +71,14 @@ pub enum PluginsState {
     Cleaned,
 }
 
+/// A plugin that must have been added by the time the app finishes
+pub(crate) struct RequiredPlugin {
+    pub(crate) type_id: TypeId,
+    pub(crate) name: &'static str,
+    /// Where the requirement was registered
+    pub(crate) location: &'static Location<'static>,
+}
+
 /// A dummy plugin that's to temporarily occupy an entry in an app's plugin registry
 pub(crate) struct PlaceholderPlugin;
 
@@ -67,23 +96,56 @@ impl<Marker, T> Plugins<Marker> for T where T: sealed::Plugins<Marker> {}
 
 mod sealed {
     use crate::{App, Plugin, app::AppError};
+    use variadics_please::all_tuples;
 
     pub trait Plugins<Marker> {
-        fn add_to_app(self, app: &mut App);
+        fn collect(self, plugins: &mut Vec<Box<dyn Plugin>>);
+
+        #[track_caller]
+        fn add_to_app(self, app: &mut App)
+        where
+            Self: Sized,
+        {
+            let mut plugins = Vec::new();
+            self.collect(&mut plugins);
+            match app.add_boxed_plugins(plugins) {
+                Ok(_) => {}
+                Err(AppError::DuplicatePlugin { plugin_name }) => panic!(
+                    "Error adding plugin {plugin_name}: : plugin was already added in application"
+                ),
+                Err(error) => panic!("Error adding plugins: {error}"),
+            }
+        }
     }
 
     pub struct PluginMarker;
+    pub struct PluginsTupleMarker;
 
     impl<P: Plugin> Plugins<PluginMarker> for P {
-        #[track_caller]
-        fn add_to_app(self, app: &mut App) {
-            if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                app.add_boxed_plugin(Box::new(self))
+        fn collect(self, plugins: &mut Vec<Box<dyn Plugin>>) {
+            plugins.push(Box::new(self));
+        }
+    }
+
+    macro_rules! impl_plugins_tuples {
+        ($(#[$meta:meta])* $(($param: ident, $plugins: ident)),*) => {
+            $(#[$meta])*
+            #[allow(
+                non_snake_case,
+                unused_variables,
+                reason = "Certain variable names are provided by the caller, not by us."
+            )]
+            impl<$($param, $plugins),*> Plugins<(PluginsTupleMarker, $($param,)*)> for ($($plugins,)*)
+            where
+                $($plugins: Plugins<$param>),*
             {
-                panic!(
-                    "Error adding plugin {plugin_name}: : plugin was already added in application"
-                )
+                fn collect(self, plugins: &mut Vec<Box<dyn Plugin>>) {
+                    let ($($plugins,)*) = self;
+                    $($plugins.collect(plugins);)*
+                }
             }
         }
     }
+
+    all_tuples!(impl_plugins_tuples, 0, 15, P, S);
 }
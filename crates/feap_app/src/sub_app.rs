@@ -1,11 +1,14 @@
-use crate::{plugin::PluginsState, App, Plugin};
+use crate::{
+    plugin::{PluginsState, RequiredPlugin},
+    App, Plugin,
+};
 use feap_core::collections::{HashMap, HashSet};
 use feap_ecs::{
     intern::Interned,
     resource::Resource,
     schedule::{
-        InternedScheduleLabel, InternedSystemSet, IntoScheduleConfigs, Schedule, ScheduleLabel,
-        Schedules,
+        InternedScheduleLabel, InternedSystemSet, IntoScheduleConfigs, Schedule,
+        ScheduleBuildError, ScheduleLabel, Schedules,
     },
     system::ScheduleSystem,
     world::{FromWorld, World},
@@ -39,6 +42,8 @@ pub struct SubApp {
     /// Panics if an update is attempted while plugins are building
     pub(crate) plugin_build_depth: usize,
     pub(crate) plugins_state: PluginsState,
+    /// Plugins which must have been added before [`App::finish`]
+    pub(crate) required_plugins: Vec<RequiredPlugin>,
     /// The schedule that will be run by [`update`]
     pub update_schedule: Option<InternedScheduleLabel>,
 }
@@ -53,6 +58,7 @@ impl Default for SubApp {
             plugin_names: HashSet::default(),
             plugin_build_depth: 0,
             plugins_state: PluginsState::Adding,
+            required_plugins: Vec::new(),
             update_schedule: None,
         }
     }
@@ -76,6 +82,33 @@ impl SubApp {
         core::mem::swap(self, &mut app.sub_apps.main);
     }
 
+    /// Returns a reference to the [`World`]
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Returns a mutable reference to the [`World`]
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Returns `true` if a plugin of type `T` has been added to this app
+    pub fn is_plugin_added<T: Plugin>(&self) -> bool {
+        self.plugin_registry
+            .iter()
+            .any(|plugin| (**plugin).as_any().is::<T>())
+    }
+
+    /// Returns the first required plugin that has not been added, if any
+    pub(crate) fn missing_required_plugin(&self) -> Option<&RequiredPlugin> {
+        self.required_plugins.iter().find(|required| {
+            !self
+                .plugin_registry
+                .iter()
+                .any(|plugin| (**plugin).as_any().type_id() == required.type_id)
+        })
+    }
+
     /// Returns `true` if there is no plugin in the middle of being built.
     pub(crate) fn is_building_plugins(&self) -> bool {
         self.plugin_build_depth > 0
@@ -108,6 +141,7 @@ impl SubApp {
         self
     }
 
+    #[track_caller]
     pub fn add_systems<M>(
         &mut self,
         schedule: impl ScheduleLabel,
@@ -134,6 +168,13 @@ impl SubApp {
         self
     }
 
+    /// See [`App::insert_resource`]
+    #[track_caller]
+    pub fn insert_resource<R: Resource>(&mut self, resource: R) -> &mut Self {
+        self.world.insert_resource(resource);
+        self
+    }
+
     /// Runs [`Plugin::finish`] for each plugin
     pub fn finish(&mut self) {
         for i in 0..self.plugin_registry.len() {
@@ -150,6 +191,13 @@ impl SubApp {
         self.plugins_state = PluginsState::Cleaned;
     }
 
+    /// Builds and initializes every schedule of this sub-app ahead of its first run
+    ///
+    /// See [`World::initialize_schedules`]
+    pub fn initialize_schedules(&mut self) -> Result<(), ScheduleBuildError> {
+        self.world_mut().initialize_schedules()
+    }
+
     /// Runs the default schedule
     /// Does not clear internal trackers used for change detection
     pub fn run_default_schedule(&mut self) {
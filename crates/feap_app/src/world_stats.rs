@@ -0,0 +1,22 @@
+use crate::{App, Plugin, main_schedule::Last};
+use feap_ecs::world::{World, WorldStats};
+
+#[cfg(test)]
+mod tests;
+
+/// Refreshes the [`WorldStats`] resource of the world
+pub fn update_world_stats(world: &mut World) {
+    WorldStats::update(world);
+}
+
+/// Initializes the [`WorldStats`] resource and refreshes it at the end of every frame,
+/// in the [`Last`] schedule
+#[derive(Default)]
+pub struct WorldStatsPlugin;
+
+impl Plugin for WorldStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldStats>()
+            .add_systems(Last, update_world_stats);
+    }
+}
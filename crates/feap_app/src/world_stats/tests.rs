@@ -0,0 +1,22 @@
+//! Tests for the [`WorldStatsPlugin`]
+
+use super::WorldStatsPlugin;
+use crate::AppTest;
+use feap_ecs::world::WorldStats;
+
+#[test]
+fn stats_are_refreshed_every_update() {
+    let mut app = AppTest::new();
+    app.add_plugins(WorldStatsPlugin);
+    app.assert_resource_eq(&WorldStats::default());
+
+    app.update();
+    let stats = *app.resource::<WorldStats>();
+    assert_eq!(stats.entities, 0);
+    assert_eq!(stats.component_types, app.world().components().len());
+    assert!(stats.live_worlds >= 1);
+
+    app.world_mut().reserve_entities(64);
+    app.update()
+        .assert_resource(|stats: &WorldStats| stats.entity_capacity >= 64);
+}
@@ -4,6 +4,24 @@
 // #[doc(inline)]
 // pub use crate::disabled;
 
+/// Picks the first branch whose condition holds, similar to a `match` over `cfg` conditions
+///
+/// Conditions are either `#[cfg(...)]` attributes or alias macros such as [`std`] and [`alloc`].
+/// The last arm must be a `_ => { ... }` fallback, so that every configuration compiles one branch.
+///
+/// ```ignore
+/// feap_core::cfg::switch! {
+///     #[cfg(target_has_atomic = "64")] => {
+///         type Cursor = i64;
+///     }
+///     _ => {
+///         type Cursor = isize;
+///     }
+/// }
+/// ```
+#[doc(inline)]
+pub use crate::switch;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! enabled {
@@ -31,28 +49,34 @@ macro_rules! switch {
     };
     (
         $cond:path => $output:tt
-        $($( $rest:tt )+)?
+    ) => {
+        ::core::compile_error!("`switch!` requires a `_ => { ... }` fallback as its last arm");
+    };
+    (
+        $cond:path => $output:tt
+        $( $rest:tt )+
     ) => {
         $cond! {
             if {
                 $crate::switch! { _ => $output }
             } else {
-                $(
-                    $crate::switch! { $($rest)+ }
-                )?
+                $crate::switch! { $($rest)+ }
             }
         }
     };
     (
         #[cfg($cfg:meta)] => $output:tt
-        $($( $rest:tt )+)?
+    ) => {
+        ::core::compile_error!("`switch!` requires a `_ => { ... }` fallback as its last arm");
+    };
+    (
+        #[cfg($cfg:meta)] => $output:tt
+        $( $rest:tt )+
     ) => {
         #[cfg($cfg)]
         $crate::switch! { _ => $output }
-        $(
-            #[cfg(not($cfg))]
-            $crate::switch! { $($rest)+ }
-        )?
+        #[cfg(not($cfg))]
+        $crate::switch! { $($rest)+ }
     };
 }
 
@@ -4,7 +4,7 @@
 //!
 
 pub use atomic_64::{AtomicI64, AtomicU64};
-pub use atomic_ptr::AtomicUsize;
+pub use atomic_ptr::{AtomicIsize, AtomicUsize};
 pub use core::sync::atomic::Ordering;
 
 #[cfg(target_has_atomic = "64")]
@@ -2,5 +2,5 @@ pub mod atomic;
 mod poison;
 mod rwlock;
 
-pub use poison::PoisonError;
+pub use poison::{LockResult, PoisonError};
 pub use rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
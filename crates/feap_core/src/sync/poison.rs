@@ -1,9 +1,58 @@
 //! Provides `LockResult`, `PoisonError`, `TryLockError`, `TryLockResult`
 
-pub use implementation::PoisonError;
+pub use implementation::{LockResult, PoisonError};
 
 #[cfg(feature = "std")]
 use std::sync as implementation;
 
 #[cfg(not(feature = "std"))]
-mod implementation {}
+mod implementation {
+    use core::fmt;
+
+    /// Fallback implementation of `PoisonError` from the standard library
+    ///
+    /// The `no_std` locks never poison, so this is only ever constructed to keep
+    /// the `std` signatures
+    pub struct PoisonError<T> {
+        guard: T,
+    }
+
+    impl<T> fmt::Debug for PoisonError<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PoisonError").finish_non_exhaustive()
+        }
+    }
+
+    impl<T> fmt::Display for PoisonError<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            "poisoned lock: another task failed inside".fmt(f)
+        }
+    }
+
+    impl<T> core::error::Error for PoisonError<T> {}
+
+    impl<T> PoisonError<T> {
+        /// Creates a `PoisonError`
+        pub fn new(guard: T) -> Self {
+            Self { guard }
+        }
+
+        /// Consumes this error indicating that a lock is poisoned, returning the underlying guard
+        pub fn into_inner(self) -> T {
+            self.guard
+        }
+
+        /// Reaches into this error indicating that a lock is poisoned, returning a reference to the underlying guard
+        pub fn get_ref(&self) -> &T {
+            &self.guard
+        }
+
+        /// Reaches into this error indicating that a lock is poisoned, returning a mutable reference to the underlying guard
+        pub fn get_mut(&mut self) -> &mut T {
+            &mut self.guard
+        }
+    }
+
+    /// Fallback implementation of `LockResult` from the standard library
+    pub type LockResult<Guard> = Result<Guard, PoisonError<Guard>>;
+}
@@ -6,4 +6,135 @@ pub use implementation::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::sync as implementation;
 
 #[cfg(not(feature = "std"))]
-mod implementation {}
+mod implementation {
+    use crate::sync::{
+        LockResult,
+        atomic::{AtomicUsize, Ordering},
+    };
+    use core::{
+        cell::UnsafeCell,
+        fmt,
+        hint::spin_loop,
+        ops::{Deref, DerefMut},
+    };
+
+    /// Set while a writer holds the lock
+    const WRITER: usize = 1;
+    /// Added to the state for every active reader
+    const READER: usize = 1 << 1;
+
+    /// Fallback implementation of `RwLock` from the standard library, using a spin lock
+    ///
+    /// The lock never poisons, so every `LockResult` it returns is `Ok`
+    pub struct RwLock<T: ?Sized> {
+        state: AtomicUsize,
+        data: UnsafeCell<T>,
+    }
+
+    unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+    unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+    impl<T> RwLock<T> {
+        /// Creates a new instance of an `RwLock<T>` which is unlocked
+        pub const fn new(value: T) -> Self {
+            Self {
+                state: AtomicUsize::new(0),
+                data: UnsafeCell::new(value),
+            }
+        }
+
+        /// Consumes this `RwLock`, returning the underlying data
+        pub fn into_inner(self) -> LockResult<T> {
+            Ok(self.data.into_inner())
+        }
+    }
+
+    impl<T: ?Sized> RwLock<T> {
+        /// Locks this `RwLock` with shared read access, spinning until it can be acquired
+        pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
+            loop {
+                let state = self.state.fetch_add(READER, Ordering::Acquire);
+                if state & WRITER == 0 {
+                    return Ok(RwLockReadGuard { lock: self });
+                }
+                self.state.fetch_sub(READER, Ordering::Release);
+                while self.state.load(Ordering::Relaxed) & WRITER != 0 {
+                    spin_loop();
+                }
+            }
+        }
+
+        /// Locks this `RwLock` with exclusive write access, spinning until it can be acquired
+        pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+            while self
+                .state
+                .compare_exchange_weak(0, WRITER, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                spin_loop();
+            }
+            Ok(RwLockWriteGuard { lock: self })
+        }
+
+        /// Returns a mutable reference to the underlying data
+        pub fn get_mut(&mut self) -> LockResult<&mut T> {
+            Ok(self.data.get_mut())
+        }
+    }
+
+    impl<T: Default> Default for RwLock<T> {
+        fn default() -> Self {
+            Self::new(T::default())
+        }
+    }
+
+    impl<T: ?Sized> fmt::Debug for RwLock<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("RwLock").finish_non_exhaustive()
+        }
+    }
+
+    /// RAII structure used to release the shared read access of a lock when dropped
+    pub struct RwLockReadGuard<'a, T: ?Sized> {
+        lock: &'a RwLock<T>,
+    }
+
+    impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            unsafe { &*self.lock.data.get() }
+        }
+    }
+
+    impl<T: ?Sized> Drop for RwLockReadGuard<'_, T> {
+        fn drop(&mut self) {
+            self.lock.state.fetch_sub(READER, Ordering::Release);
+        }
+    }
+
+    /// RAII structure used to release the exclusive write access of a lock when dropped
+    pub struct RwLockWriteGuard<'a, T: ?Sized> {
+        lock: &'a RwLock<T>,
+    }
+
+    impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            unsafe { &*self.lock.data.get() }
+        }
+    }
+
+    impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            unsafe { &mut *self.lock.data.get() }
+        }
+    }
+
+    impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
+        fn drop(&mut self) {
+            self.lock.state.fetch_and(!WRITER, Ordering::Release);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use feap_core::collections::HashMap;
+use feap_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use std::hint::black_box;
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct Unit;
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct Numbered(u32);
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+enum Phase {
+    First,
+    Update,
+    Last,
+}
+
+/// Mirrors the `Schedules` map looked up by `run_schedule` each frame
+fn schedule_map() -> (
+    HashMap<InternedScheduleLabel, usize>,
+    Vec<InternedScheduleLabel>,
+) {
+    let mut labels = vec![
+        Unit.intern(),
+        Phase::First.intern(),
+        Phase::Update.intern(),
+        Phase::Last.intern(),
+    ];
+    labels.extend((0..28).map(|i| Numbered(i).intern()));
+    let map = labels
+        .iter()
+        .enumerate()
+        .map(|(i, &label)| (label, i))
+        .collect();
+    (map, labels)
+}
+
+fn label_lookup(c: &mut Criterion) {
+    let (map, labels) = schedule_map();
+
+    c.bench_function("interned_label_lookup", |b| {
+        b.iter(|| {
+            for label in &labels {
+                black_box(map.get(black_box(label)));
+            }
+        });
+    });
+
+    c.bench_function("intern_and_lookup", |b| {
+        b.iter(|| black_box(map.get(&black_box(Phase::Update).intern())));
+    });
+}
+
+criterion_group!(benches, label_lookup);
+criterion_main!(benches);
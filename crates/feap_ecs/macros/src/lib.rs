@@ -5,8 +5,8 @@ mod message;
 
 use feap_macro_utils::{derive_label, FeapManifest};
 use proc_macro::TokenStream;
-use quote::format_ident;
-use syn::{parse_macro_input, DeriveInput};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
 pub(crate) fn feap_ecs_path() -> syn::Path {
     FeapManifest::shared(|manifest| manifest.get_path("feap_ecs"))
@@ -28,14 +28,47 @@ pub fn derive_schedule_label(input: TokenStream) -> TokenStream {
 
 /// Derive macro generating an impl of the trait `SystemSet`.
 ///
+/// For enums without generics whose variants have no fields, including enums with explicit
+/// discriminants, an impl of `SystemSetVariants` listing every variant is generated as well.
+///
 /// This does not work for unions.
 #[proc_macro_derive(SystemSet)]
 pub fn derive_system_set(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let mut trait_path = feap_ecs_path();
     trait_path.segments.push(format_ident!("schedule").into());
+    let variants = system_set_variants(&input, &trait_path);
     trait_path.segments.push(format_ident!("SystemSet").into());
-    derive_label(input, "SystemSet", &trait_path)
+    let mut output = derive_label(input, "SystemSet", &trait_path);
+    output.extend(TokenStream::from(variants));
+    output
+}
+
+/// Generates the `SystemSetVariants` impl of a fieldless enum, or nothing for other types
+fn system_set_variants(
+    input: &DeriveInput,
+    schedule_path: &syn::Path,
+) -> proc_macro2::TokenStream {
+    let Data::Enum(data) = &input.data else {
+        return quote! {};
+    };
+    if !input.generics.params.is_empty()
+        || data.variants.is_empty()
+        || data
+            .variants
+            .iter()
+            .any(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return quote! {};
+    }
+
+    let ident = &input.ident;
+    let variants = data.variants.iter().map(|variant| &variant.ident);
+    quote! {
+        impl #schedule_path::SystemSetVariants for #ident {
+            const VARIANTS: &'static [Self] = &[#(Self::#variants),*];
+        }
+    }
 }
 
 #[proc_macro_derive(
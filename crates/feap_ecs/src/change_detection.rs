@@ -3,6 +3,7 @@
 use crate::{
     component::{Tick, TickCells},
     resource::Resource,
+    world::AccessGuard,
 };
 use core::{
     marker::PhantomData,
@@ -125,6 +126,19 @@ impl MaybeLocation {
 }
 
 impl<T> MaybeLocation<T> {
+    /// Converts into an [`Option`], which is `None` if the `track_location` feature is disabled
+    #[inline]
+    pub fn into_option(self) -> Option<T> {
+        feap_core::cfg::switch! {
+            #[cfg(feature = "track_location")] => {
+                Some(self.value)
+            }
+            _ => {
+                None
+            }
+        }
+    }
+
     /// Maps an `MaybeLocation<T> `to `MaybeLocation<U>` by applying a function to a contained value.
     #[inline]
     pub fn map<U>(self, _f: impl FnOnce(T) -> U) -> MaybeLocation<U> {
@@ -228,6 +242,9 @@ pub struct Mut<'w, T: ?Sized> {
     pub(crate) value: &'w mut T,
     pub(crate) ticks: TicksMut<'w>,
     pub(crate) changed_by: MaybeLocation<&'w mut &'static Location<'static>>,
+    /// Releases the borrow checked in debug builds when this is dropped
+    #[expect(dead_code, reason = "only held to be dropped along with the borrow")]
+    pub(crate) access: AccessGuard,
 }
 
 change_detection_impl!(Mut<'w, T>, T,);
@@ -240,6 +257,8 @@ pub struct MutUntyped<'w> {
     pub(crate) value: PtrMut<'w>,
     pub(crate) ticks: TicksMut<'w>,
     pub(crate) changed_by: MaybeLocation<&'w mut &'static Location<'static>>,
+    /// Releases the borrow checked in debug builds when this is dropped
+    pub(crate) access: AccessGuard,
 }
 
 impl<'w> MutUntyped<'w> {
@@ -249,6 +268,7 @@ impl<'w> MutUntyped<'w> {
             value: unsafe { self.value.deref_mut() },
             ticks: self.ticks,
             changed_by: self.changed_by,
+            access: self.access,
         }
     }
 }
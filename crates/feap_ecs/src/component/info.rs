@@ -21,12 +21,25 @@ impl ComponentInfo {
         ComponentInfo { id, descriptor }
     }
 
+    /// Returns a value uniquely identifying the current component
+    #[inline]
+    pub fn id(&self) -> ComponentId {
+        self.id
+    }
+
     /// Returns the name of the current component.
     #[inline]
     pub fn name(&self) -> DebugName {
         self.descriptor.name.clone()
     }
 
+    /// Returns the [`TypeId`] of the underlying component type.
+    /// Returns `None` if the component does not correspond to a Rust type
+    #[inline]
+    pub fn type_id(&self) -> Option<TypeId> {
+        self.descriptor.type_id
+    }
+
     /// Returns the layout used to store values of this component in memory.
     #[inline]
     pub fn layout(&self) -> Layout {
@@ -44,11 +57,29 @@ impl ComponentInfo {
         self.descriptor.drop
     }
 
+    /// Returns a value indicating the storage strategy for the current component
+    #[inline]
+    pub fn storage_type(&self) -> StorageType {
+        self.descriptor.storage_type
+    }
+
     /// Returns `true` if the underlying component type can be freely shared between threads
     #[inline]
     pub fn is_send_and_sync(&self) -> bool {
         self.descriptor.is_send_and_sync
     }
+
+    /// Returns `true` if the current component is mutable
+    #[inline]
+    pub fn mutable(&self) -> bool {
+        self.descriptor.mutable
+    }
+
+    /// Returns the [`ComponentCloneBehavior`] of the current component
+    #[inline]
+    pub fn clone_behavior(&self) -> &ComponentCloneBehavior {
+        &self.descriptor.clone_behavior
+    }
 }
 
 /// A value which uniquely identifies the type of [`Component`] or [`Resource`] within a [`World`]
@@ -169,12 +200,47 @@ impl Components {
         debug_assert!(prev.is_none());
     }
 
+    /// Returns the number of components registered with this instance
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns `true` if there are no components registered with this instance
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
     /// Gets the metadata associated with the given component, if it is registered
     #[inline]
     pub fn get_info(&self, id: ComponentId) -> Option<&ComponentInfo> {
         self.components.get(id.0).and_then(|info| info.as_ref())
     }
 
+    /// Gets the metadata associated with the given component
+    /// Panics if the component is not registered
+    #[inline]
+    #[track_caller]
+    pub fn info(&self, id: ComponentId) -> &ComponentInfo {
+        match self.get_info(id) {
+            Some(info) => info,
+            None => panic!("Component with id {id:?} is not registered in this `Components`"),
+        }
+    }
+
+    /// Gets the name of the component with this [`ComponentId`] if it is present
+    #[inline]
+    pub fn get_name(&self, id: ComponentId) -> Option<DebugName> {
+        self.get_info(id).map(ComponentInfo::name)
+    }
+
+    /// Returns an iterator over the metadata of all registered components and resources
+    #[inline]
+    pub fn iter_registered(&self) -> impl Iterator<Item = &ComponentInfo> + '_ {
+        self.components.iter().filter_map(Option::as_ref)
+    }
+
     /// Type-erased equivalent of [`Components::valid_resource_id()`]
     #[inline]
     pub fn get_valid_resource_id(&self, type_id: TypeId) -> Option<ComponentId> {
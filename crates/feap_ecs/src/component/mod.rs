@@ -6,6 +6,9 @@ mod register;
 mod required;
 mod tick;
 
+#[cfg(test)]
+mod tests;
+
 pub use feap_ecs_macros::Component;
 pub use info::*;
 pub use register::*;
@@ -2,22 +2,75 @@ use super::{ComponentDescriptor, ComponentId, Components};
 use crate::resource::Resource;
 use alloc::vec::Vec;
 use core::{any::TypeId, fmt::Debug, ops::Deref};
-use feap_core::sync::PoisonError;
+use feap_core::sync::{atomic::Ordering, PoisonError};
 use feap_utils::map::TypeIdMap;
 
 /// Generates [`ComponentId`]s
 #[derive(Debug, Default)]
 pub struct ComponentIds {
     next: feap_core::sync::atomic::AtomicUsize,
+    soft_limit: Option<usize>,
+}
+
+/// The error type returned by [`ComponentIds::try_next_mut`] if the supply of unique
+/// [`ComponentId`]s has been exhausted
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("More components have been registered than supported ({allocated} ids allocated)")]
+pub struct ComponentIdExhaustedError {
+    /// The number of [`ComponentId`]s allocated when the registration failed
+    pub allocated: usize,
 }
 
 impl ComponentIds {
     /// Generates and returns the next [`ComponentId`]
+    ///
+    /// Panics if the supply of unique [`ComponentId`]s has been exhausted
+    #[track_caller]
     pub fn next_mut(&mut self) -> ComponentId {
+        self.try_next_mut().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Generates and returns the next [`ComponentId`], or returns an error if the
+    /// supply of unique ids has been exhausted
+    pub fn try_next_mut(&mut self) -> Result<ComponentId, ComponentIdExhaustedError> {
         let id = self.next.get_mut();
+        let next = id
+            .checked_add(1)
+            .ok_or(ComponentIdExhaustedError { allocated: *id })?;
         let result = ComponentId(*id);
-        *id += 1;
-        result
+        *id = next;
+        if let Some(limit) = self.soft_limit
+            && next == limit.saturating_add(1)
+        {
+            log::warn!(
+                "{next} component ids have been allocated, more than the soft limit of {limit}"
+            );
+        }
+        Ok(result)
+    }
+
+    /// Sets the number of [`ComponentId`]s above which a warning is logged, or `None` to
+    /// never warn
+    ///
+    /// The warning is logged once, when the limit is first exceeded. Unlike the exhaustion of ids,
+    /// this does not prevent registrations; it helps to notice a registry that keeps growing
+    pub fn set_soft_limit(&mut self, limit: Option<usize>) {
+        self.soft_limit = limit;
+    }
+
+    /// Returns `true` if more [`ComponentId`]s have been generated than the soft limit allows
+    pub fn exceeds_soft_limit(&self) -> bool {
+        self.soft_limit.is_some_and(|limit| self.len() > limit)
+    }
+
+    /// Returns the number of [`ComponentId`]s generated so far
+    pub fn len(&self) -> usize {
+        self.next.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if no [`ComponentId`] has been generated yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 }
 
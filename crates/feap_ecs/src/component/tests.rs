@@ -0,0 +1,80 @@
+//! Tests for change [`Tick`]s and their wraparound handling
+
+use super::{CheckChangeTicks, ComponentTicks, Tick, MAX_CHANGE_AGE};
+
+#[test]
+fn ticks_newer_than_last_run_are_detected() {
+    let (last_run, this_run) = (Tick::new(3), Tick::new(10));
+    assert!(Tick::new(5).is_newer_than(last_run, this_run));
+    assert!(Tick::new(10).is_newer_than(last_run, this_run));
+    assert!(!Tick::new(3).is_newer_than(last_run, this_run));
+    assert!(!Tick::new(2).is_newer_than(last_run, this_run));
+}
+
+#[test]
+fn ticks_are_compared_across_u32_wraparound() {
+    let (last_run, this_run) = (Tick::new(u32::MAX - 5), Tick::new(4));
+    assert!(Tick::new(u32::MAX - 1).is_newer_than(last_run, this_run));
+    assert!(Tick::new(2).is_newer_than(last_run, this_run));
+    assert!(!Tick::new(u32::MAX - 10).is_newer_than(last_run, this_run));
+}
+
+#[test]
+fn ages_are_clamped_to_max_change_age() {
+    let this_run = Tick::new(u32::MAX);
+    let tick = Tick::new(u32::MAX - MAX_CHANGE_AGE - 10);
+    // Older than the tick, so it would be detected if ages were not clamped
+    let last_run = Tick::new(u32::MAX - MAX_CHANGE_AGE - 20);
+    assert!(!tick.is_newer_than(last_run, this_run));
+
+    let last_run = Tick::new(u32::MAX - MAX_CHANGE_AGE + 1);
+    assert!(Tick::new(u32::MAX - 1).is_newer_than(last_run, this_run));
+}
+
+#[test]
+fn check_tick_clamps_ticks_older_than_max() {
+    let check = CheckChangeTicks(Tick::new(MAX_CHANGE_AGE + 100));
+
+    let mut old = Tick::new(0);
+    assert!(old.check_tick(check));
+    assert_eq!(old, Tick::new(100));
+    assert_eq!(check.present_tick().relative_to(old), Tick::MAX);
+    assert!(!old.check_tick(check));
+
+    let mut recent = Tick::new(MAX_CHANGE_AGE);
+    assert!(!recent.check_tick(check));
+    assert_eq!(recent, Tick::new(MAX_CHANGE_AGE));
+}
+
+#[test]
+fn check_tick_clamps_across_u32_wraparound() {
+    let check = CheckChangeTicks(Tick::new(5));
+    let mut old = Tick::new(5u32.wrapping_sub(MAX_CHANGE_AGE).wrapping_sub(1));
+    assert!(old.check_tick(check));
+    assert_eq!(old, Tick::new(5u32.wrapping_sub(MAX_CHANGE_AGE)));
+}
+
+#[test]
+fn component_ticks_track_additions_and_changes() {
+    let mut ticks = ComponentTicks::new(Tick::new(10));
+    assert!(ticks.is_added(Tick::new(5), Tick::new(20)));
+    assert!(ticks.is_changed(Tick::new(5), Tick::new(20)));
+
+    ticks.set_changed(Tick::new(30));
+    assert!(!ticks.is_added(Tick::new(25), Tick::new(40)));
+    assert!(ticks.is_changed(Tick::new(25), Tick::new(40)));
+}
+
+#[test]
+fn checked_component_ticks_stay_undetected() {
+    let mut ticks = ComponentTicks::new(Tick::new(10));
+    let present = Tick::new(10u32.wrapping_add(u32::MAX - 1));
+    ticks.check_ticks(CheckChangeTicks(present));
+    assert_eq!(present.relative_to(ticks.added), Tick::MAX);
+    assert_eq!(present.relative_to(ticks.changed), Tick::MAX);
+
+    // Ages are clamped too, so even older systems must not see the clamped ticks as new
+    let last_run = Tick::new(present.get().wrapping_sub(MAX_CHANGE_AGE + 5));
+    assert!(!ticks.is_added(last_run, present));
+    assert!(!ticks.is_changed(last_run, present));
+}
@@ -1,5 +1,6 @@
 use crate::event::Event;
 use core::cell::UnsafeCell;
+use feap_core::ptr::UnsafeCellDeref;
 
 /// The (arbitrarily chosen) minimum number of world tick increments between `check_tick` scans.
 ///
@@ -28,6 +29,7 @@ impl Tick {
     /// The value of this is equal to [`MAX_CHANGE_AGE`]
     pub const MAX: Self = Self::new(MAX_CHANGE_AGE);
 
+    /// Creates a new [`Tick`] wrapping the given value
     #[inline]
     pub const fn new(tick: u32) -> Self {
         Self { tick }
@@ -39,6 +41,28 @@ impl Tick {
         self.tick
     }
 
+    /// Sets the value of this change tick
+    #[inline]
+    pub fn set(&mut self, tick: u32) {
+        self.tick = tick;
+    }
+
+    /// Returns `true` if this `Tick` occurred since the system's `last_run`
+    ///
+    /// `this_run` is the current tick of the system, used as a reference to handle wraparound
+    #[inline]
+    pub fn is_newer_than(self, last_run: Tick, this_run: Tick) -> bool {
+        // This works even with wraparound because the world tick (`this_run`) is always "newer" than
+        // `last_run` and `self.tick`, and we scan periodically to clamp `ComponentTicks` values
+        // so they never get older than `u32::MAX` (the difference would overflow).
+        //
+        // The clamp here ensures determinism (since scans could differ between app runs).
+        let ticks_since_insert = this_run.relative_to(self).tick.min(MAX_CHANGE_AGE);
+        let ticks_since_system = this_run.relative_to(last_run).tick.min(MAX_CHANGE_AGE);
+
+        ticks_since_system > ticks_since_insert
+    }
+
     /// Returns a change tick representing the relationship between `self` and `other`
     #[inline]
     pub fn relative_to(self, other: Self) -> Self {
@@ -47,11 +71,14 @@ impl Tick {
     }
 
     /// Wraps this change tick's value if it exceeds [`Tick::MAX`]
+    ///
+    /// Returns `true` if wrapping was performed. Otherwise, returns `false`
     #[inline]
     pub fn check_tick(&mut self, check: CheckChangeTicks) -> bool {
         let age = check.present_tick().relative_to(*self);
         if age.get() > Self::MAX.get() {
-            todo!()
+            *self = check.present_tick().relative_to(Self::MAX);
+            true
         } else {
             false
         }
@@ -72,6 +99,9 @@ impl CheckChangeTicks {
 }
 
 /// Interior-mutable access to the [`Tick`] for a single component or resource
+///
+/// Custom storage backends keep one pair of `UnsafeCell<Tick>` per value and hand out
+/// `TickCells` to build change-detecting smart pointers such as [`Mut`](crate::change_detection::Mut)
 #[derive(Copy, Clone, Debug)]
 pub struct TickCells<'a> {
     /// The tick indicating when the value was added to the world.
@@ -80,7 +110,31 @@ pub struct TickCells<'a> {
     pub changed: &'a UnsafeCell<Tick>,
 }
 
+impl<'a> TickCells<'a> {
+    /// Creates a new [`TickCells`] from the cells storing the added and changed ticks of a value
+    #[inline]
+    pub fn new(added: &'a UnsafeCell<Tick>, changed: &'a UnsafeCell<Tick>) -> Self {
+        Self { added, changed }
+    }
+
+    /// Reads the current ticks
+    ///
+    /// # Safety
+    /// Neither cell may be mutably aliased while this is called
+    #[inline]
+    pub unsafe fn read(&self) -> ComponentTicks {
+        ComponentTicks {
+            added: unsafe { self.added.read() },
+            changed: unsafe { self.changed.read() },
+        }
+    }
+}
+
 /// Records when a component or resource was added and when it was last mutably dereferenced
+///
+/// Storage backends should create the ticks with [`ComponentTicks::new`] on insertion, call
+/// [`ComponentTicks::set_changed`] on every mutable access and clamp both ticks on every
+/// [`CheckChangeTicks`] event with [`ComponentTicks::check_ticks`]
 #[derive(Copy, Clone, Debug)]
 pub struct ComponentTicks {
     /// Tick recording the time this component or resource was added
@@ -88,3 +142,43 @@ pub struct ComponentTicks {
     /// Tick recording the time this component or resource was most recently changed
     pub changed: Tick,
 }
+
+impl ComponentTicks {
+    /// Creates a new instance with the same change tick for `added` and `changed`
+    #[inline]
+    pub fn new(change_tick: Tick) -> Self {
+        Self {
+            added: change_tick,
+            changed: change_tick,
+        }
+    }
+
+    /// Returns `true` if the component or resource was added after the system last ran
+    #[inline]
+    pub fn is_added(&self, last_run: Tick, this_run: Tick) -> bool {
+        self.added.is_newer_than(last_run, this_run)
+    }
+
+    /// Returns `true` if the component or resource was added or mutably dereferenced after the
+    /// system last ran
+    #[inline]
+    pub fn is_changed(&self, last_run: Tick, this_run: Tick) -> bool {
+        self.changed.is_newer_than(last_run, this_run)
+    }
+
+    /// Manually sets the change tick
+    ///
+    /// This is normally done automatically via the [`DerefMut`](core::ops::DerefMut)
+    /// implementation on [`Mut<T>`](crate::change_detection::Mut)
+    #[inline]
+    pub fn set_changed(&mut self, change_tick: Tick) {
+        self.changed = change_tick;
+    }
+
+    /// Clamps both ticks so that they never become older than [`Tick::MAX`]
+    #[inline]
+    pub fn check_ticks(&mut self, check: CheckChangeTicks) {
+        self.added.check_tick(check);
+        self.changed.check_tick(check);
+    }
+}
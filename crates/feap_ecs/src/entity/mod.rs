@@ -1,5 +1,8 @@
 mod map_entities;
 
+#[cfg(test)]
+mod tests;
+
 pub use map_entities::*;
 
 use crate::{
@@ -12,12 +15,25 @@ use core::{
     fmt::{Debug, Display},
     hash::Hash,
     hash::Hasher,
+    mem,
+    sync::atomic::Ordering as AtomicOrdering,
 };
 use derive_more::derive::Display;
-#[cfg(target_has_atomic = "64")]
-use feap_core::sync::atomic::AtomicI64 as AtomicIdCursor;
 use nonmax::NonMaxU32;
 
+feap_core::cfg::switch! {
+    #[cfg(target_has_atomic = "64")] => {
+        use feap_core::sync::atomic::AtomicI64 as AtomicIdCursor;
+        /// The signed type backing the free cursor of [`Entities`]
+        type IdCursor = i64;
+    }
+    _ => {
+        use feap_core::sync::atomic::AtomicIsize as AtomicIdCursor;
+        /// The signed type backing the free cursor of [`Entities`]
+        type IdCursor = isize;
+    }
+}
+
 /// This represents the row or `index` of an [`Entity`] within the [`Entities`] table.
 /// This is a lighter weight version of [`Entity`]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
@@ -27,6 +43,12 @@ pub struct EntityRow(NonMaxU32);
 impl EntityRow {
     const PLACEHOLDER: Self = Self(NonMaxU32::MAX);
 
+    /// Constructs a new [`EntityRow`] from its index
+    #[inline(always)]
+    pub const fn new(index: NonMaxU32) -> Self {
+        Self(index)
+    }
+
     /// Gets some bits that represent this value
     #[inline(always)]
     const fn to_bits(self) -> u32 {
@@ -55,6 +77,14 @@ impl EntityGeneration {
     pub const fn to_bits(self) -> u32 {
         self.0
     }
+
+    /// Returns the generation that comes `versions` versions after this one,
+    /// and whether the generation wrapped around and could alias an earlier one
+    #[inline]
+    pub const fn after_versions_and_could_alias(self, versions: u32) -> (Self, bool) {
+        let (generation, wrapped) = self.0.overflowing_add(versions);
+        (Self(generation), wrapped)
+    }
 }
 
 /// Lightweight identifier of an [`Entity`]
@@ -186,8 +216,15 @@ pub struct Entities {
     ///          free_cursor   pending.len()
     /// ```
     ///
+    /// As IDs are allocated, `free_cursor` is atomically decremented, moving
+    /// items from the freelist into the reserved list by sliding over the boundary.
+    /// Once the freelist runs out, `free_cursor` starts going negative.
+    /// The more negative it is, the more IDs have been reserved starting exactly at
+    /// the end of `meta.len()`.
     pending: Vec<EntityRow>,
     free_cursor: AtomicIdCursor,
+    /// The number of entities that are allocated and flushed
+    len: u32,
 }
 
 impl Entities {
@@ -196,17 +233,169 @@ impl Entities {
             meta: Vec::new(),
             pending: Vec::new(),
             free_cursor: AtomicIdCursor::new(0),
+            len: 0,
+        }
+    }
+
+    /// Reserves an entity ID concurrently
+    ///
+    /// Storage for the entity is allocated on the next call to [`Entities::flush`]
+    pub fn reserve_entity(&self) -> Entity {
+        let n = self.free_cursor.fetch_sub(1, AtomicOrdering::Relaxed);
+        if n > 0 {
+            // Allocate from the freelist
+            let row = self.pending[(n - 1) as usize];
+            Entity::from_row_and_generation(row, self.meta[row.index() as usize].generation)
+        } else {
+            // Grab a new ID, outside the range of `meta.len()`. `flush()` must
+            // eventually be called to make it valid.
+            //
+            // As `self.free_cursor` goes more and more negative, we return IDs farther
+            // and farther beyond `meta.len()`.
+            let raw = self.meta.len() as IdCursor - n;
+            Entity::from_row(new_row(raw).expect("too many entities"))
+        }
+    }
+
+    /// Checks that we do not have pending work requiring `flush()` to be called
+    fn verify_flushed(&mut self) {
+        debug_assert!(
+            !self.needs_flush(),
+            "flush() needs to be called before this operation is legal"
+        );
+    }
+
+    /// Allocates an entity ID directly
+    pub fn alloc(&mut self) -> Entity {
+        self.verify_flushed();
+        self.len += 1;
+        if let Some(row) = self.pending.pop() {
+            let new_free_cursor = self.pending.len() as IdCursor;
+            *self.free_cursor.get_mut() = new_free_cursor;
+            Entity::from_row_and_generation(row, self.meta[row.index() as usize].generation)
+        } else {
+            let row = new_row(self.meta.len() as IdCursor).expect("too many entities");
+            self.meta.push(EntityMeta::EMPTY);
+            Entity::from_row(row)
         }
     }
 
+    /// Destroys an entity, allowing it to be reused
+    ///
+    /// Returns the location of the entity, or `None` if `entity` was not allocated
+    /// (or was already freed)
+    pub fn free(&mut self, entity: Entity) -> Option<EntityIdLocation> {
+        self.verify_flushed();
+
+        let meta = self.meta.get_mut(entity.index() as usize)?;
+        if meta.generation != entity.generation {
+            return None;
+        }
+
+        let (new_generation, wrapped) = meta.generation.after_versions_and_could_alias(1);
+        meta.generation = new_generation;
+        if wrapped {
+            log::warn!(
+                "Entity({}) generation wrapped on Entities::free, aliasing may occur",
+                entity.row()
+            );
+        }
+
+        let location = mem::replace(&mut meta.location, EntityMeta::EMPTY.location);
+
+        self.pending.push(entity.row());
+
+        let new_free_cursor = self.pending.len() as IdCursor;
+        *self.free_cursor.get_mut() = new_free_cursor;
+        self.len -= 1;
+        Some(location)
+    }
+
+    /// Ensures at least `additional` entities can be allocated without reallocating
+    pub fn reserve(&mut self, additional: u32) {
+        self.verify_flushed();
+
+        let freelist_size = *self.free_cursor.get_mut();
+        let shortfall = additional as IdCursor - freelist_size;
+        if shortfall > 0 {
+            self.meta.reserve(shortfall as usize);
+        }
+    }
+
+    /// Returns `true` if the [`Entities`] contains [`entity`](Entity)
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.resolve_from_id(entity.row())
+            .is_some_and(|e| e.generation() == entity.generation())
+    }
+
+    /// Clears all [`Entity`]s from the world
+    pub fn clear(&mut self) {
+        self.meta.clear();
+        self.pending.clear();
+        *self.free_cursor.get_mut() = 0;
+        self.len = 0;
+    }
+
+    /// Returns the location of an [`Entity`]
+    ///
+    /// Note: for pending entities and entities not participating in the ECS (entities with
+    /// an [`EntityIdLocation`] of `None`), returns `None`
+    #[inline]
+    pub fn get(&self, entity: Entity) -> Option<EntityLocation> {
+        self.get_id_location(entity).flatten()
+    }
+
+    /// Returns the [`EntityIdLocation`] of an [`Entity`], or `None` if the entity does not exist
+    #[inline]
+    pub fn get_id_location(&self, entity: Entity) -> Option<EntityIdLocation> {
+        self.meta
+            .get(entity.index() as usize)
+            .filter(|meta| meta.generation == entity.generation)
+            .map(|meta| meta.location)
+    }
+
+    /// Gets the current generation of the row, returning the [`Entity`] it refers to
+    ///
+    /// Returns `None` if the row has never been allocated or reserved
+    pub fn resolve_from_id(&self, row: EntityRow) -> Option<Entity> {
+        let idu = row.index() as usize;
+        if let Some(&EntityMeta { generation, .. }) = self.meta.get(idu) {
+            Some(Entity::from_row_and_generation(row, generation))
+        } else {
+            // `id` is outside of the meta list - check whether it is reserved but not yet flushed.
+            let free_cursor = self.free_cursor.load(AtomicOrdering::Relaxed);
+            // If this entity was manually created, then free_cursor might be positive
+            // Returns None entity will never be returned by `reserve_entity`
+            let num_pending = usize::try_from(-free_cursor).ok()?;
+            (idu < self.meta.len() + num_pending).then_some(Entity::from_row(row))
+        }
+    }
+
+    /// Returns the source code location and the tick at which the row of `entity` was last
+    /// spawned, or `None` if the row was never allocated
+    pub fn spawned_or_despawned(&self, entity: Entity) -> Option<(MaybeLocation, Tick)> {
+        self.meta
+            .get(entity.index() as usize)
+            .map(|meta| (meta.spawned_or_despawned.by, meta.spawned_or_despawned.tick))
+    }
+
+    fn needs_flush(&mut self) -> bool {
+        *self.free_cursor.get_mut() != self.pending.len() as IdCursor
+    }
+
     /// Allocates space for entities previously reserved with [`reserve_entity`],
     /// then initializes each one using the supplied function
     ///
+    /// [`reserve_entity`]: Entities::reserve_entity
+    ///
+    /// # Safety
+    /// Flush _must_ set the entity location to the correct archetype for the entity,
+    /// or `None` if the entity does not participate in the ECS
     pub unsafe fn flush(
         &mut self,
-        _init: impl FnMut(Entity, &mut EntityIdLocation),
-        _by: MaybeLocation,
-        _tick: Tick,
+        mut init: impl FnMut(Entity, &mut EntityIdLocation),
+        by: MaybeLocation,
+        tick: Tick,
     ) {
         let free_cursor = self.free_cursor.get_mut();
         let current_free_cursor = *free_cursor;
@@ -214,24 +403,122 @@ impl Entities {
         let new_free_cursor = if current_free_cursor >= 0 {
             current_free_cursor as usize
         } else {
-            todo!()
+            let old_meta_len = self.meta.len();
+            let new_meta_len = old_meta_len + -current_free_cursor as usize;
+            self.meta.resize(new_meta_len, EntityMeta::EMPTY);
+            for (row, meta) in self.meta.iter_mut().enumerate().skip(old_meta_len) {
+                let row = new_row(row as IdCursor).expect("too many entities");
+                init(
+                    Entity::from_row_and_generation(row, meta.generation),
+                    &mut meta.location,
+                );
+                meta.spawned_or_despawned = SpawnedOrDespawned { by, tick };
+            }
+
+            self.len += -current_free_cursor as u32;
+            *free_cursor = 0;
+            0
         };
 
-        for _row in self.pending.drain(new_free_cursor..) {
-            todo!()
+        self.len += (self.pending.len() - new_free_cursor) as u32;
+        for row in self.pending.drain(new_free_cursor..) {
+            let meta = &mut self.meta[row.index() as usize];
+            init(
+                Entity::from_row_and_generation(row, meta.generation),
+                &mut meta.location,
+            );
+            meta.spawned_or_despawned = SpawnedOrDespawned { by, tick };
         }
     }
 
+    /// Returns the number of entities that are currently alive
+    ///
+    /// Entities reserved but not flushed yet are not counted
+    #[inline]
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if there are no entities alive
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total number of rows that were ever allocated, including freed ones
+    #[inline]
+    pub fn total_count(&self) -> usize {
+        self.meta.len()
+    }
+
+    /// Returns the number of rows that are allocated or reserved, without the freelist
+    #[inline]
+    pub fn used_count(&self) -> usize {
+        (self.meta.len() as IdCursor - self.free_cursor.load(AtomicOrdering::Relaxed)) as usize
+    }
+
+    /// Returns the number of rows the entity table can hold without reallocating
     #[inline]
-    pub(crate) fn check_change_ticks(&mut self, _check: CheckChangeTicks) {
-        for _meta in &mut self.meta {
-            todo!()
+    pub fn capacity(&self) -> usize {
+        self.meta.capacity()
+    }
+
+    /// Releases the memory reserved for rows that were never allocated
+    ///
+    /// Freed rows are kept, since their generation is needed to detect stale [`Entity`]s
+    pub fn shrink_to_fit(&mut self) {
+        self.verify_flushed();
+
+        self.meta.shrink_to_fit();
+        self.pending.shrink_to_fit();
+    }
+
+    #[inline]
+    pub(crate) fn check_change_ticks(&mut self, check: CheckChangeTicks) {
+        for meta in &mut self.meta {
+            meta.spawned_or_despawned.tick.check_tick(check);
         }
     }
 }
 
+/// Converts a raw index into an [`EntityRow`], or `None` if it is out of range
+#[inline]
+fn new_row(index: IdCursor) -> Option<EntityRow> {
+    u32::try_from(index)
+        .ok()
+        .and_then(NonMaxU32::new)
+        .map(EntityRow::new)
+}
+
+/// Metadata of a single entity row
+#[derive(Copy, Clone, Debug)]
+struct EntityMeta {
+    /// The current [`EntityGeneration`] of the [`EntityRow`]
+    generation: EntityGeneration,
+    /// The current location of the [`EntityRow`]
+    location: EntityIdLocation,
+    /// Location and tick of the last spawn or despawn
+    spawned_or_despawned: SpawnedOrDespawned,
+}
+
+impl EntityMeta {
+    /// Meta for an entity row that has never been allocated
+    const EMPTY: EntityMeta = EntityMeta {
+        generation: EntityGeneration::FIRST,
+        location: None,
+        spawned_or_despawned: SpawnedOrDespawned {
+            by: MaybeLocation::caller(),
+            tick: Tick::new(0),
+        },
+    };
+}
+
+/// Records where and when an entity was last spawned or despawned
 #[derive(Copy, Clone, Debug)]
-struct EntityMeta {}
+struct SpawnedOrDespawned {
+    by: MaybeLocation,
+    tick: Tick,
+}
 
 /// A location of an entity in an archetype
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -0,0 +1,119 @@
+//! Property tests for entity allocation in [`Entities`]
+
+use super::{Entities, Entity};
+use crate::{change_detection::MaybeLocation, component::Tick};
+use alloc::vec::Vec;
+use proptest::prelude::*;
+
+/// An operation applied to [`Entities`] and to the reference model
+#[derive(Clone, Debug)]
+enum Op {
+    Alloc,
+    /// Frees the alive entity at this position (modulo the number of alive entities)
+    Free(usize),
+    /// Frees the alive entity at this position a second time, after freeing it once
+    DoubleFree(usize),
+    Reserve(u8),
+    Flush,
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => Just(Op::Alloc),
+        2 => any::<usize>().prop_map(Op::Free),
+        1 => any::<usize>().prop_map(Op::DoubleFree),
+        2 => (1u8..8).prop_map(Op::Reserve),
+        1 => Just(Op::Flush),
+    ]
+}
+
+/// Flushes `entities`, returning the entities that were initialized
+fn flush(entities: &mut Entities) -> Vec<Entity> {
+    let mut flushed = Vec::new();
+    // SAFETY: no archetypes exist, so `None` is the correct location for every entity
+    unsafe {
+        entities.flush(
+            |entity, location| {
+                *location = None;
+                flushed.push(entity);
+            },
+            MaybeLocation::caller(),
+            Tick::new(0),
+        );
+    }
+    flushed
+}
+
+proptest! {
+    #[test]
+    fn interleaved_operations_match_model(ops in proptest::collection::vec(op(), 0..128)) {
+        let mut entities = Entities::new();
+        let mut alive: Vec<Entity> = Vec::new();
+        let mut reserved: Vec<Entity> = Vec::new();
+        let mut freed: Vec<Entity> = Vec::new();
+
+        for op in ops {
+            // `alloc` and `free` require pending reservations to be flushed first
+            if !matches!(op, Op::Reserve(_)) && !reserved.is_empty() {
+                let mut flushed = flush(&mut entities);
+                flushed.sort();
+                reserved.sort();
+                prop_assert_eq!(&flushed, &reserved);
+                alive.append(&mut reserved);
+            }
+            match op {
+                Op::Alloc => {
+                    let entity = entities.alloc();
+                    prop_assert!(!alive.contains(&entity));
+                    alive.push(entity);
+                }
+                Op::Free(index) | Op::DoubleFree(index) if !alive.is_empty() => {
+                    let entity = alive.swap_remove(index % alive.len());
+                    prop_assert_eq!(entities.free(entity), Some(None));
+                    prop_assert!(!entities.contains(entity));
+                    if matches!(op, Op::DoubleFree(_)) {
+                        prop_assert_eq!(entities.free(entity), None);
+                    }
+                    freed.push(entity);
+                }
+                Op::Free(_) | Op::DoubleFree(_) => {}
+                Op::Reserve(count) => {
+                    for _ in 0..count {
+                        let entity = entities.reserve_entity();
+                        prop_assert!(!alive.contains(&entity) && !reserved.contains(&entity));
+                        prop_assert!(entities.resolve_from_id(entity.row()).is_some());
+                        reserved.push(entity);
+                    }
+                }
+                Op::Flush => {}
+            }
+
+            prop_assert_eq!(entities.len() as usize, alive.len());
+            prop_assert_eq!(entities.is_empty(), alive.is_empty());
+            prop_assert_eq!(entities.used_count(), alive.len() + reserved.len());
+            prop_assert!(entities.total_count() >= alive.len());
+            for entity in &alive {
+                prop_assert!(entities.contains(*entity));
+            }
+            for entity in &freed {
+                prop_assert!(!entities.contains(*entity));
+            }
+        }
+    }
+
+    #[test]
+    fn freed_rows_are_reused_with_a_new_generation(count in 1usize..32) {
+        let mut entities = Entities::new();
+        let first: Vec<Entity> = (0..count).map(|_| entities.alloc()).collect();
+        for entity in &first {
+            entities.free(*entity);
+        }
+        let second: Vec<Entity> = (0..count).map(|_| entities.alloc()).collect();
+
+        prop_assert_eq!(entities.total_count(), count);
+        for entity in &second {
+            let previous = first.iter().find(|e| e.row() == entity.row());
+            prop_assert!(previous.is_some_and(|previous| previous.generation() != entity.generation()));
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::feap_error::FeapError;
-use crate::{component::Tick, resource::Resource};
+use crate::{component::Tick, resource::Resource, schedule::InternedScheduleLabel};
+use alloc::format;
 use core::fmt::Display;
 // use  derive_more::derive::{Deref, DerefMut};
 use feap_utils::debug_info::DebugName;
@@ -14,6 +15,11 @@ pub enum ErrorContext {
         /// The last tick that the system was run
         last_run: Tick,
     },
+    /// The error occurred while building a schedule
+    Schedule {
+        /// The label of the schedule that was built
+        label: InternedScheduleLabel,
+    },
 }
 
 impl Display for ErrorContext {
@@ -22,6 +28,9 @@ impl Display for ErrorContext {
             Self::System { name, .. } => {
                 write!(f, "System `{name}` failed")
             }
+            Self::Schedule { label } => {
+                write!(f, "Schedule `{label:?}` failed to build")
+            }
         }
     }
 }
@@ -31,6 +40,7 @@ impl ErrorContext {
     pub fn name(&self) -> DebugName {
         match self {
             Self::System { name, .. } => name.clone(),
+            Self::Schedule { label } => DebugName::owned(format!("{label:?}")),
         }
     }
 
@@ -38,6 +48,7 @@ impl ErrorContext {
     pub fn kind(&self) -> &str {
         match self {
             Self::System { .. } => "system",
+            Self::Schedule { .. } => "schedule",
         }
     }
 }
@@ -75,3 +86,10 @@ macro_rules! inner {
 pub fn panic(error: FeapError, ctx: ErrorContext) {
     inner!(panic, error, ctx);
 }
+
+/// Error handler that logs the system error at the `warn` level.
+#[track_caller]
+#[inline]
+pub fn warn(error: FeapError, ctx: ErrorContext) {
+    inner!(log::warn, error, ctx);
+}
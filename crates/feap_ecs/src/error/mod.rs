@@ -1,4 +1,4 @@
 mod feap_error;
 mod handler;
 
-pub use {handler::{DefaultErrorHandler, ErrorHandler, ErrorContext}, feap_error::FeapError};
+pub use {handler::{warn, DefaultErrorHandler, ErrorHandler, ErrorContext}, feap_error::FeapError};
@@ -115,22 +115,26 @@ macro_rules! define_label {
             }
 
             fn ref_eq(&self, other: &Self) -> bool {
-                use ::core::ptr;
+                use ::core::{mem, ptr};
 
-                // Test that both the type id and pointer address are equivalent.
-                self.type_id() == other.type_id()
-                    && ptr::addr_eq(ptr::from_ref::<Self>(self), ptr::from_ref::<Self>(other))
+                // Leaked labels that take up space never share an address, so comparing
+                // pointers is enough. Zero-sized labels all live at a dangling address though,
+                // so for those the type id is compared as well.
+                ptr::addr_eq(ptr::from_ref::<Self>(self), ptr::from_ref::<Self>(other))
+                    && (mem::size_of_val(self) != 0 || self.type_id() == other.type_id())
             }
 
             fn ref_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
-                use ::core::{hash::Hash, ptr};
+                use ::core::{hash::Hash, mem, ptr};
 
-                // Hash the type id...
-                self.type_id().hash(state);
-
-                // ...and the pointer address.
+                // Hash the pointer address.
                 // Cast to a unit `()` first to discard any pointer metadata.
                 ptr::from_ref::<Self>(self).cast::<()>().hash(state);
+
+                // Zero-sized labels share their address, so add the type id to tell them apart.
+                if mem::size_of_val(self) == 0 {
+                    self.type_id().hash(state);
+                }
             }
         }
 
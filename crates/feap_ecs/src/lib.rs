@@ -12,12 +12,12 @@ extern crate std;
 pub mod change_detection;
 pub mod component;
 mod entity;
-mod error;
+pub mod error;
 mod event;
 pub mod intern;
 pub mod label;
 mod lifecycle;
-mod message;
+pub mod message;
 pub mod observer;
 pub mod query;
 mod relationship;
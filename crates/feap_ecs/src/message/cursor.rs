@@ -0,0 +1,98 @@
+use crate::message::{Message, MessageInstance, Messages};
+use core::{iter::Chain, marker::PhantomData, slice::IterMut};
+
+/// Tracks which messages of type `E` have already been seen, across calls to [`Messages::update`]
+///
+/// Each reader or mutator keeps its own cursor, so messages are seen once per cursor
+#[derive(Debug)]
+pub struct MessageCursor<E: Message> {
+    last_message_count: usize,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Message> Default for MessageCursor<E> {
+    fn default() -> Self {
+        Self {
+            last_message_count: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E: Message> Clone for MessageCursor<E> {
+    fn clone(&self) -> Self {
+        Self {
+            last_message_count: self.last_message_count,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E: Message> MessageCursor<E> {
+    /// Iterates mutably over the messages this cursor has not seen yet
+    ///
+    /// Messages are marked as seen as the iterator advances
+    pub fn read_mut<'a>(&'a mut self, messages: &'a mut Messages<E>) -> MessageMutIterator<'a, E> {
+        let a_index = self
+            .last_message_count
+            .saturating_sub(messages.messages_a.start_message_count);
+        let b_index = self
+            .last_message_count
+            .saturating_sub(messages.messages_b.start_message_count);
+        let a = messages
+            .messages_a
+            .messages
+            .get_mut(a_index..)
+            .unwrap_or_default();
+        let b = messages
+            .messages_b
+            .messages
+            .get_mut(b_index..)
+            .unwrap_or_default();
+
+        MessageMutIterator {
+            iter: a.iter_mut().chain(b.iter_mut()),
+            last_message_count: &mut self.last_message_count,
+        }
+    }
+
+    /// Returns the number of messages this cursor has not seen yet
+    pub fn len(&self, messages: &Messages<E>) -> usize {
+        messages
+            .message_count
+            .saturating_sub(self.last_message_count)
+            .min(messages.len())
+    }
+
+    /// Returns `true` if this cursor has seen all messages
+    pub fn is_empty(&self, messages: &Messages<E>) -> bool {
+        self.len(messages) == 0
+    }
+
+    /// Marks all current messages as seen
+    pub fn clear(&mut self, messages: &Messages<E>) {
+        self.last_message_count = messages.message_count;
+    }
+}
+
+/// [`Iterator`] over mutable references to the unseen messages of a [`MessageCursor`]
+pub struct MessageMutIterator<'a, E: Message> {
+    iter: Chain<IterMut<'a, MessageInstance<E>>, IterMut<'a, MessageInstance<E>>>,
+    last_message_count: &'a mut usize,
+}
+
+impl<'a, E: Message> Iterator for MessageMutIterator<'a, E> {
+    type Item = &'a mut E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let instance = self.iter.next()?;
+        *self.last_message_count = instance.message_id.id + 1;
+        Some(&mut instance.message)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<E: Message> ExactSizeIterator for MessageMutIterator<'_, E> {}
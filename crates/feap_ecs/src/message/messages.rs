@@ -1,12 +1,19 @@
 use crate::{
-    message::{Message, MessageInstance},
+    change_detection::MaybeLocation,
+    message::{Message, MessageId, MessageInstance},
     resource::Resource,
 };
 use alloc::vec::Vec;
+use core::marker::PhantomData;
 
 /// A message collection that represents the messages that occurred within the last two
 /// [`Messages::update`] calls
 #[derive(Debug, Resource)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(serialize = "E: serde::Serialize", deserialize = "E: serde::Deserialize<'de>"))
+)]
 pub struct Messages<E: Message> {
     /// Holds the oldest still active messages
     pub(crate) messages_a: MessageSequence<E>,
@@ -16,7 +23,150 @@ pub struct Messages<E: Message> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(serialize = "E: serde::Serialize", deserialize = "E: serde::Deserialize<'de>"))
+)]
 pub(crate) struct MessageSequence<E: Message> {
     pub(crate) messages: Vec<MessageInstance<E>>,
     pub(crate) start_message_count: usize,
 }
+
+impl<E: Message> Default for Messages<E> {
+    fn default() -> Self {
+        Self {
+            messages_a: Default::default(),
+            messages_b: Default::default(),
+            message_count: 0,
+        }
+    }
+}
+
+impl<E: Message> Default for MessageSequence<E> {
+    fn default() -> Self {
+        Self {
+            messages: Vec::new(),
+            start_message_count: 0,
+        }
+    }
+}
+
+impl<E: Message> Messages<E> {
+    /// Sends `message` into the current message buffer
+    #[track_caller]
+    pub fn send(&mut self, message: E) -> MessageId<E> {
+        self.send_with_caller(message, MaybeLocation::caller())
+    }
+
+    pub(crate) fn send_with_caller(&mut self, message: E, caller: MaybeLocation) -> MessageId<E> {
+        let message_id = MessageId {
+            id: self.message_count,
+            caller,
+            _marker: PhantomData,
+        };
+        self.messages_b.messages.push(MessageInstance {
+            message_id,
+            message,
+        });
+        self.message_count += 1;
+        message_id
+    }
+
+    /// Sends a batch of messages into the current message buffer
+    ///
+    /// The buffer grows once for the whole batch, as far as the iterator's size hint allows.
+    /// Returns the ids of the sent messages, which are consecutive
+    #[track_caller]
+    pub fn send_batch(&mut self, messages: impl IntoIterator<Item = E>) -> SendBatchIds<E> {
+        self.send_batch_with_caller(messages, MaybeLocation::caller())
+    }
+
+    pub(crate) fn send_batch_with_caller(
+        &mut self,
+        messages: impl IntoIterator<Item = E>,
+        caller: MaybeLocation,
+    ) -> SendBatchIds<E> {
+        let last_count = self.message_count;
+        let messages = messages.into_iter();
+        let buffer = &mut self.messages_b.messages;
+        let start_len = buffer.len();
+        buffer.reserve(messages.size_hint().0);
+        buffer.extend(
+            messages
+                .zip(last_count..)
+                .map(|(message, id)| MessageInstance {
+                    message_id: MessageId {
+                        id,
+                        caller,
+                        _marker: PhantomData,
+                    },
+                    message,
+                }),
+        );
+        self.message_count += buffer.len() - start_len;
+
+        SendBatchIds {
+            last_count,
+            message_count: self.message_count,
+            caller,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Swaps the message buffers and clears the oldest one
+    ///
+    /// Messages therefore stay readable for two calls to `update`
+    pub fn update(&mut self) {
+        core::mem::swap(&mut self.messages_a, &mut self.messages_b);
+        self.messages_b.messages.clear();
+        self.messages_b.start_message_count = self.message_count;
+    }
+
+    /// Returns the number of messages currently stored
+    pub fn len(&self) -> usize {
+        self.messages_a.messages.len() + self.messages_b.messages.len()
+    }
+
+    /// Returns `true` if there are no messages currently stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// [`Iterator`] over the [`MessageId`]s of a batch sent with [`Messages::send_batch`]
+pub struct SendBatchIds<E> {
+    last_count: usize,
+    message_count: usize,
+    caller: MaybeLocation,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Message> Iterator for SendBatchIds<E> {
+    type Item = MessageId<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.last_count >= self.message_count {
+            return None;
+        }
+
+        let result = MessageId {
+            id: self.last_count,
+            caller: self.caller,
+            _marker: PhantomData,
+        };
+        self.last_count += 1;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<E: Message> ExactSizeIterator for SendBatchIds<E> {
+    fn len(&self) -> usize {
+        self.message_count.saturating_sub(self.last_count)
+    }
+}
@@ -1,7 +1,16 @@
+mod cursor;
 mod messages;
+mod mutator;
+mod writer;
 
+#[cfg(test)]
+mod tests;
+
+pub use cursor::{MessageCursor, MessageMutIterator};
 pub use feap_ecs_macros::Message;
-pub use messages::Messages;
+pub use messages::{Messages, SendBatchIds};
+pub use mutator::MessageMutator;
+pub use writer::MessageWriter;
 
 use crate::change_detection::MaybeLocation;
 use core::{fmt, marker::PhantomData};
@@ -16,11 +25,17 @@ use core::{fmt, marker::PhantomData};
 pub trait Message: Send + Sync + 'static {}
 
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(serialize = "M: serde::Serialize", deserialize = "M: serde::Deserialize<'de>"))
+)]
 pub(crate) struct MessageInstance<M: Message> {
     pub message_id: MessageId<M>,
     pub message: M,
 }
 
+/// An identifier for a [`Message`], unique among the messages of its type
 pub struct MessageId<M: Message> {
     pub id: usize,
     pub caller: MaybeLocation,
@@ -41,6 +56,28 @@ impl<M: Message> fmt::Display for MessageId<M> {
     }
 }
 
+/// Only the numeric id is serialized: caller locations cannot be restored
+#[cfg(feature = "serialize")]
+impl<M: Message> serde::Serialize for MessageId<M> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.id as u64)
+    }
+}
+
+/// The caller of a deserialized id points into this `Deserialize` impl, not to where the message
+/// was sent
+#[cfg(feature = "serialize")]
+impl<'de, M: Message> serde::Deserialize<'de> for MessageId<M> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(MessageId {
+            id: usize::try_from(id).map_err(serde::de::Error::custom)?,
+            caller: MaybeLocation::caller(),
+            _marker: PhantomData,
+        })
+    }
+}
+
 impl<M: Message> fmt::Debug for MessageId<M> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -0,0 +1,71 @@
+use crate::{
+    change_detection::ResMut,
+    message::{Message, MessageCursor, MessageMutIterator, Messages},
+    query::FilteredAccessSet,
+    system::{SystemMeta, SystemParam},
+    world::World,
+};
+
+/// Mutates [`Message`]s of type `E` in place, before later readers see them
+///
+/// A system parameter wrapping mutable access to the [`Messages<E>`] resource, along with a
+/// [`MessageCursor`] owned by the system. Useful to filter or coalesce high-frequency messages,
+/// such as solver progress reports
+///
+/// Outside of systems, a mutator can be created from the buffers and a cursor with
+/// [`MessageMutator::new`]
+pub struct MessageMutator<'w, 's, E: Message> {
+    messages: &'w mut Messages<E>,
+    cursor: &'s mut MessageCursor<E>,
+}
+
+impl<'w, 's, E: Message> MessageMutator<'w, 's, E> {
+    /// Creates a mutator over `messages`, which only yields the messages `cursor` has not seen
+    pub fn new(messages: &'w mut Messages<E>, cursor: &'s mut MessageCursor<E>) -> Self {
+        Self { messages, cursor }
+    }
+
+    /// Iterates mutably over the messages this system has not seen yet
+    pub fn read(&mut self) -> MessageMutIterator<'_, E> {
+        self.cursor.read_mut(self.messages)
+    }
+
+    /// Returns the number of messages this system has not seen yet
+    pub fn len(&self) -> usize {
+        self.cursor.len(self.messages)
+    }
+
+    /// Returns `true` if this system has seen all messages
+    pub fn is_empty(&self) -> bool {
+        self.cursor.is_empty(self.messages)
+    }
+
+    /// Marks all current messages as seen, without mutating them
+    pub fn clear(&mut self) {
+        self.cursor.clear(self.messages);
+    }
+}
+
+unsafe impl<'w, 's, E: Message> SystemParam for MessageMutator<'w, 's, E> {
+    type State = (
+        <ResMut<'w, Messages<E>> as SystemParam>::State,
+        MessageCursor<E>,
+    );
+    type Item<'world, 'state> = MessageMutator<'world, 'state, E>;
+
+    fn init_state(world: &mut World) -> Self::State {
+        (
+            ResMut::<Messages<E>>::init_state(world),
+            MessageCursor::default(),
+        )
+    }
+
+    fn init_access(
+        state: &Self::State,
+        system_meta: &mut SystemMeta,
+        component_access_set: &mut FilteredAccessSet,
+        world: &mut World,
+    ) {
+        ResMut::<Messages<E>>::init_access(&state.0, system_meta, component_access_set, world);
+    }
+}
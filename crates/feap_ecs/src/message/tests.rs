@@ -0,0 +1,132 @@
+//! Tests for reading and mutating [`Messages`] across buffer updates
+
+use super::{Message, MessageCursor, MessageMutator, Messages};
+#[cfg(feature = "serialize")]
+use super::MessageId;
+use alloc::{vec, vec::Vec};
+
+#[derive(Message, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+struct Progress(u32);
+
+fn values(cursor: &mut MessageCursor<Progress>, messages: &mut Messages<Progress>) -> Vec<u32> {
+    cursor.read_mut(messages).map(|message| message.0).collect()
+}
+
+#[test]
+fn cursor_reads_each_message_once() {
+    let mut messages = Messages::default();
+    let mut cursor = MessageCursor::default();
+    messages.send(Progress(0));
+    messages.send(Progress(1));
+    assert_eq!(cursor.len(&messages), 2);
+    assert_eq!(values(&mut cursor, &mut messages), vec![0, 1]);
+    assert!(cursor.is_empty(&messages));
+
+    messages.send(Progress(2));
+    assert_eq!(values(&mut cursor, &mut messages), vec![2]);
+    assert!(values(&mut cursor, &mut messages).is_empty());
+}
+
+#[test]
+fn cursor_reads_across_one_update() {
+    let mut messages = Messages::default();
+    let mut cursor = MessageCursor::default();
+    messages.send(Progress(0));
+    messages.update();
+    messages.send(Progress(1));
+    assert_eq!(cursor.len(&messages), 2);
+    assert_eq!(values(&mut cursor, &mut messages), vec![0, 1]);
+
+    messages.update();
+    messages.send(Progress(2));
+    assert_eq!(cursor.len(&messages), 1);
+    assert_eq!(values(&mut cursor, &mut messages), vec![2]);
+}
+
+#[test]
+fn cursor_skips_messages_dropped_by_two_updates() {
+    let mut messages = Messages::default();
+    let mut cursor = MessageCursor::default();
+    messages.send(Progress(0));
+    messages.update();
+    messages.send(Progress(1));
+    messages.update();
+    messages.send(Progress(2));
+    assert_eq!(cursor.len(&messages), 2);
+    assert_eq!(values(&mut cursor, &mut messages), vec![1, 2]);
+}
+
+#[test]
+fn partially_consumed_iterator_only_marks_yielded_messages() {
+    let mut messages = Messages::default();
+    let mut cursor = MessageCursor::default();
+    messages.send_batch([Progress(0), Progress(1), Progress(2)]);
+    assert_eq!(cursor.read_mut(&mut messages).next(), Some(&mut Progress(0)));
+    messages.update();
+    assert_eq!(values(&mut cursor, &mut messages), vec![1, 2]);
+}
+
+#[test]
+fn cleared_cursor_skips_current_messages() {
+    let mut messages = Messages::default();
+    let mut cursor = MessageCursor::default();
+    messages.send(Progress(0));
+    cursor.clear(&messages);
+    assert!(cursor.is_empty(&messages));
+    messages.update();
+    messages.send(Progress(1));
+    assert_eq!(values(&mut cursor, &mut messages), vec![1]);
+}
+
+#[test]
+fn mutations_are_seen_by_later_readers() {
+    let mut messages = Messages::default();
+    let mut mutator_cursor = MessageCursor::default();
+    let mut reader_cursor = MessageCursor::default();
+    messages.send_batch([Progress(1), Progress(2)]);
+    messages.update();
+    messages.send(Progress(3));
+
+    let mut mutator = MessageMutator::new(&mut messages, &mut mutator_cursor);
+    assert_eq!(mutator.len(), 3);
+    for message in mutator.read() {
+        message.0 *= 10;
+    }
+    assert!(mutator.is_empty());
+
+    assert_eq!(values(&mut reader_cursor, &mut messages), vec![10, 20, 30]);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn messages_round_trip_through_serde() {
+    let mut messages = Messages::default();
+    messages.send(Progress(0));
+    messages.send(Progress(1));
+    messages.update();
+    messages.send(Progress(2));
+
+    let json = serde_json::to_string(&messages).unwrap();
+    let mut restored: Messages<Progress> = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    assert_eq!(values(&mut MessageCursor::default(), &mut restored), vec![0, 1, 2]);
+
+    // Both buffers are restored, so the older one is dropped first
+    restored.update();
+    assert_eq!(values(&mut MessageCursor::default(), &mut restored), vec![2]);
+    assert_eq!(restored.send(Progress(3)).id, 3);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn message_ids_serialize_as_numbers() {
+    let mut messages = Messages::default();
+    messages.send(Progress(0));
+    let id = messages.send(Progress(1));
+
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "1");
+    let restored: MessageId<Progress> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.id, id.id);
+}
@@ -0,0 +1,53 @@
+use crate::{
+    change_detection::{MaybeLocation, ResMut},
+    message::{Message, MessageId, Messages, SendBatchIds},
+    query::FilteredAccessSet,
+    system::{SystemMeta, SystemParam},
+    world::World,
+};
+
+/// Sends [`Message`]s of type `E`
+///
+/// A system parameter wrapping mutable access to the [`Messages<E>`] resource
+pub struct MessageWriter<'w, E: Message> {
+    messages: ResMut<'w, Messages<E>>,
+}
+
+impl<'w, E: Message> MessageWriter<'w, E> {
+    /// Sends a new [`Message`]
+    #[track_caller]
+    pub fn send(&mut self, message: E) -> MessageId<E> {
+        self.messages
+            .value
+            .send_with_caller(message, MaybeLocation::caller())
+    }
+
+    /// Sends a batch of [`Message`]s, reserving space for all of them at once
+    ///
+    /// Prefer this over calling [`MessageWriter::send`] in a loop, e.g. when sending one
+    /// message per entity
+    #[track_caller]
+    pub fn send_batch(&mut self, messages: impl IntoIterator<Item = E>) -> SendBatchIds<E> {
+        self.messages
+            .value
+            .send_batch_with_caller(messages, MaybeLocation::caller())
+    }
+}
+
+unsafe impl<'w, E: Message> SystemParam for MessageWriter<'w, E> {
+    type State = <ResMut<'w, Messages<E>> as SystemParam>::State;
+    type Item<'world, 'state> = MessageWriter<'world, E>;
+
+    fn init_state(world: &mut World) -> Self::State {
+        ResMut::<Messages<E>>::init_state(world)
+    }
+
+    fn init_access(
+        state: &Self::State,
+        system_meta: &mut SystemMeta,
+        component_access_set: &mut FilteredAccessSet,
+        world: &mut World,
+    ) {
+        ResMut::<Messages<E>>::init_access(state, system_meta, component_access_set, world);
+    }
+}
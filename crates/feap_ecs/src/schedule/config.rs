@@ -1,5 +1,8 @@
 use crate::{
-    schedule::{BoxedCondition, Chain, GraphInfo, InternedSystemSet, SystemSet},
+    schedule::{
+        graph::{Dependency, DependencyKind},
+        BoxedCondition, Chain, GraphInfo, InternedSystemSet, IntoSystemSet, SystemSet,
+    },
     system::{BoxedSystem, IntoSystem, ScheduleSystem},
 };
 use alloc::{boxed::Box, vec, vec::Vec};
@@ -89,6 +92,72 @@ impl<T: Schedulable<Metadata = GraphInfo, GroupMetadata = Chain>> ScheduleConfig
         };
         self
     }
+
+    fn add_in_set(&mut self, set: InternedSystemSet) {
+        match self {
+            Self::ScheduleConfig(config) => {
+                config.metadata.hierarchy.push(set);
+            }
+            Self::Configs { configs, .. } => {
+                for config in configs {
+                    config.add_in_set(set);
+                }
+            }
+        }
+    }
+
+    #[track_caller]
+    fn in_set_inner(mut self, set: impl SystemSet) -> Self {
+        assert!(
+            set.system_type().is_none(),
+            "adding arbitrary systems to a system type set is not allowed"
+        );
+
+        self.add_in_set(set.intern());
+        self
+    }
+
+    fn add_dependency(&mut self, kind: DependencyKind, set: InternedSystemSet) {
+        match self {
+            Self::ScheduleConfig(config) => {
+                config
+                    .metadata
+                    .dependencies
+                    .push(Dependency::new(kind, set));
+            }
+            Self::Configs { configs, .. } => {
+                for config in configs {
+                    config.add_dependency(kind, set);
+                }
+            }
+        }
+    }
+
+    fn before_inner(mut self, set: InternedSystemSet) -> Self {
+        self.add_dependency(DependencyKind::Before, set);
+        self
+    }
+
+    fn after_inner(mut self, set: InternedSystemSet) -> Self {
+        self.add_dependency(DependencyKind::After, set);
+        self
+    }
+
+    fn distinct_inner(mut self) -> Self {
+        self.set_distinct();
+        self
+    }
+
+    fn set_distinct(&mut self) {
+        match self {
+            Self::ScheduleConfig(config) => {
+                config.metadata.distinct = true;
+            }
+            Self::Configs { configs, .. } => {
+                configs.iter_mut().for_each(Self::set_distinct);
+            }
+        }
+    }
 }
 
 /// Types that can convert into a [`ScheduleConfig`]
@@ -104,6 +173,26 @@ pub trait IntoScheduleConfigs<T: Schedulable<Metadata = GraphInfo, GroupMetadata
     /// Convert into a [`ScheduleConfigs`]
     fn into_configs(self) -> ScheduleConfigs<T>;
 
+    /// Add these systems to the provided `set`
+    #[track_caller]
+    fn in_set(self, set: impl SystemSet) -> ScheduleConfigs<T> {
+        self.into_configs().in_set(set)
+    }
+
+    /// Runs before all systems in `set`
+    ///
+    /// Functions and systems can be passed directly, to order against all their instances
+    fn before<M>(self, set: impl IntoSystemSet<M>) -> ScheduleConfigs<T> {
+        self.into_configs().before(set)
+    }
+
+    /// Runs after all systems in `set`
+    ///
+    /// Functions and systems can be passed directly, to order against all their instances
+    fn after<M>(self, set: impl IntoSystemSet<M>) -> ScheduleConfigs<T> {
+        self.into_configs().after(set)
+    }
+
     /// Treat this collection as a sequence of systems
     ///
     /// Ordering constraints will be applied between the successive elements
@@ -112,6 +201,17 @@ pub trait IntoScheduleConfigs<T: Schedulable<Metadata = GraphInfo, GroupMetadata
     fn chain(self) -> ScheduleConfigs<T> {
         self.into_configs().chain()
     }
+
+    /// Place each system in its own anonymous set, instead of the [`SystemTypeSet`] shared by
+    /// all instances of its function
+    ///
+    /// Ordering relative to a function is ambiguous once that function is added more than once.
+    /// Duplicates marked as distinct are left out of its set, so they no longer cause the ambiguity
+    ///
+    /// [`SystemTypeSet`]: super::SystemTypeSet
+    fn distinct(self) -> ScheduleConfigs<T> {
+        self.into_configs().distinct()
+    }
 }
 
 impl<T: Schedulable<Metadata = GraphInfo, GroupMetadata = Chain>> IntoScheduleConfigs<T, ()>
@@ -121,9 +221,26 @@ impl<T: Schedulable<Metadata = GraphInfo, GroupMetadata = Chain>> IntoScheduleCo
         self
     }
 
+    #[track_caller]
+    fn in_set(self, set: impl SystemSet) -> ScheduleConfigs<T> {
+        self.in_set_inner(set)
+    }
+
+    fn before<M>(self, set: impl IntoSystemSet<M>) -> ScheduleConfigs<T> {
+        self.before_inner(set.into_system_set().intern())
+    }
+
+    fn after<M>(self, set: impl IntoSystemSet<M>) -> ScheduleConfigs<T> {
+        self.after_inner(set.into_system_set().intern())
+    }
+
     fn chain(self) -> ScheduleConfigs<T> {
         self.chain_inner()
     }
+
+    fn distinct(self) -> ScheduleConfigs<T> {
+        self.distinct_inner()
+    }
 }
 
 impl<F, Marker> IntoScheduleConfigs<ScheduleSystem, Marker> for F
@@ -1,8 +1,10 @@
 use crate::{
-    schedule::{ScheduleGraph, node::NodeId},
+    change_detection::MaybeLocation,
+    schedule::{node::{NodeId, SystemSetKey}, ScheduleGraph},
     world::World,
 };
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
 
 /// Category of errors encountered during [`Schedule::initialize`]
 #[non_exhaustive]
@@ -10,6 +12,10 @@ use alloc::{string::String, vec::Vec};
 pub enum ScheduleBuildError {
     #[error("`{0:?}` and `{1:?}` have both `in_set` and `before`-`after` relationships (these might be transitive). This combination is unsolvable as a system cannot run before or after a set it belongs to.")]
     CrossDependency(NodeId, NodeId),
+    #[error("`{0:?}` and `{1:?}` have a `before`-`after` relationship (which may be transitive) but share systems.")]
+    SetsHaveOrderButIntersect(SystemSetKey, SystemSetKey),
+    #[error("Tried to order against `{0:?}` in a schedule that has more than one `{0:?}` instance. `{0:?}` is a `SystemTypeSet` and cannot be used for ordering if ambiguous. Use a different set without this restriction, or call `.distinct()` on the duplicates. Instances were added at: {locations}", locations = display_locations(.1))]
+    SystemTypeSetAmbiguity(SystemSetKey, Vec<MaybeLocation>),
     #[error("Tried to run a schedule before all of its systems have been initialized.")]
     Uninitialized,
     #[error(transparent)]
@@ -25,7 +31,43 @@ impl ScheduleBuildError {
     /// should be used as those used to [`initialize`] the [`Schedule`].
     /// Failure to do so will result in incorrect or incomplete error messages
     pub fn to_string(&self, graph: &ScheduleGraph, world: &World) -> String {
-        todo!()
+        match self {
+            ScheduleBuildError::CrossDependency(a, b) => format!(
+                "{} `{}` and {} `{}` have both `in_set` and `before`-`after` relationships (these might be transitive). This combination is unsolvable as a system cannot run before or after a set it belongs to.",
+                a.kind(),
+                graph.get_node_name(a),
+                b.kind(),
+                graph.get_node_name(b)
+            ),
+            ScheduleBuildError::SetsHaveOrderButIntersect(a, b) => format!(
+                "`{}` and `{}` have a `before`-`after` relationship (which may be transitive) but share systems.",
+                graph.get_node_name(&NodeId::Set(*a)),
+                graph.get_node_name(&NodeId::Set(*b))
+            ),
+            ScheduleBuildError::SystemTypeSetAmbiguity(key, locations) => {
+                let name = graph.get_node_name(&NodeId::Set(*key));
+                format!(
+                    "Tried to order against `{name}` in a schedule that has more than one `{name}` instance. `{name}` is a `SystemTypeSet` and cannot be used for ordering if ambiguous. Use a different set without this restriction, or call `.distinct()` on the duplicates. Instances were added at: {}",
+                    display_locations(locations)
+                )
+            }
+            ScheduleBuildError::Uninitialized => format!("{self}"),
+            ScheduleBuildError::Elevated(warning) => warning.to_string(graph, world),
+        }
+    }
+}
+
+/// Joins the locations where the instances of a system were added, if they were tracked
+fn display_locations(locations: &[MaybeLocation]) -> String {
+    let locations: Vec<String> = locations
+        .iter()
+        .filter_map(|location| location.into_option())
+        .map(|location| format!("{location}"))
+        .collect();
+    if locations.is_empty() {
+        String::from("unknown (enable the `track_location` feature to record them)")
+    } else {
+        locations.join(", ")
     }
 }
 
@@ -42,12 +84,23 @@ pub enum ScheduleBuildWarning {
 impl ScheduleBuildWarning {
     /// Renders the warning as a human readable string with node identifiers
     /// replaced with their names
-    pub fn to_string(&self, graph: &ScheduleGraph, world: &World) -> String {
-        // match self {
-        //     Self::HierarchRedundancy(transitive_edges) => {
-        //         ScheduleBuildError::hierarchy_redundancy_to_string(transitive_edges, graph)
-        //     }
-        // }
-        todo!()
+    pub fn to_string(&self, graph: &ScheduleGraph, _world: &World) -> String {
+        match self {
+            Self::HierarchyRedundancy(transitive_edges) => {
+                let mut message =
+                    String::from("The hierarchy of system sets contains redundant edges:");
+                for (parent, child) in transitive_edges {
+                    let _ = write!(
+                        message,
+                        "\n -- {} `{}` cannot be child of {} `{}`, longer path exists",
+                        child.kind(),
+                        graph.get_node_name(child),
+                        parent.kind(),
+                        graph.get_node_name(parent)
+                    );
+                }
+                message
+            }
+        }
     }
 }
@@ -1,13 +1,18 @@
+#[cfg(feature = "std")]
 mod multi_threaded;
 mod single_threaded;
 
+#[cfg(feature = "std")]
 pub(super) use multi_threaded::*;
 pub(super) use single_threaded::*;
 
 use crate::{
     error::{ErrorContext, FeapError},
-    schedule::node::{ConditionWithAccess, SystemKey, SystemSetKey, SystemWithAccess},
-    system::System,
+    schedule::{
+        node::{ConditionWithAccess, SystemKey, SystemSetKey, SystemWithAccess},
+        InternedScheduleLabel,
+    },
+    system::{ScheduleSystem, System},
     world::World,
 };
 use alloc::vec::Vec;
@@ -33,23 +38,62 @@ pub enum ExecutorKind {
 }
 
 /// Types that can run a [`SystemSchedule`] on a [`World`]
-pub(super) trait SystemExecutor: Send + Sync {
+///
+/// The built-in executors are selected with [`Schedule::set_executor_kind`]. A custom executor,
+/// such as a priority-based scheduler, can be plugged into a schedule with [`Schedule::set_executor`]
+///
+/// [`Schedule::set_executor_kind`]: super::Schedule::set_executor_kind
+/// [`Schedule::set_executor`]: super::Schedule::set_executor
+pub trait SystemExecutor: Send + Sync + 'static {
+    /// Returns the [`ExecutorKind`] that is closest to the behavior of this executor
     fn kind(&self) -> ExecutorKind;
+
+    /// Prepares the executor for `schedule`, called after every rebuild of the schedule
     fn init(&mut self, schedule: &SystemSchedule);
+
+    /// Runs the systems of `schedule` on `world`
+    ///
+    /// Systems in `skip_systems` must not run. Errors returned by systems are passed to
+    /// `error_handler`, and `hooks` are notified around each system that runs
     fn run(
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
         skip_systems: Option<&FixedBitSet>,
         error_handler: fn(FeapError, ErrorContext),
+        hooks: Option<&mut dyn ExecutorHooks>,
     );
 }
 
+/// Callbacks invoked while a [`Schedule`] runs, set with [`Schedule::set_executor_hooks`]
+///
+/// Lets profilers and diagnostics observe system execution without changing the executors.
+/// All methods default to doing nothing
+///
+/// [`Schedule`]: super::Schedule
+/// [`Schedule::set_executor_hooks`]: super::Schedule::set_executor_hooks
+pub trait ExecutorHooks: Send + Sync + 'static {
+    /// Called before the executor starts running the schedule
+    fn on_schedule_start(&mut self, _label: InternedScheduleLabel) {}
+
+    /// Called right before `system` runs. Systems skipped by their run conditions are not reported
+    fn on_system_start(&mut self, _system: &ScheduleSystem) {}
+
+    /// Called right after `system` has run
+    ///
+    /// With the `std` feature, this is also called when `system` panics, before the panic is
+    /// resumed. Without it, a panicking system never reaches this hook
+    fn on_system_end(&mut self, _system: &ScheduleSystem) {}
+}
+
 /// Holds systems and conditions of a [`Schedule`] sorted in topological order
 /// (along with dependency information for `multi-threaded` execution).
 ///
 /// Since the arrays are sorted in the same order, elements are referenced by their index
 /// [`FixedBitSet`] is used as a smaller, more efficient substitute of `HashSet<usize>`
+///
+/// Custom executors access the arrays through slices, so they can mutate the systems and
+/// conditions in place but cannot add, remove or reorder them
 #[derive(Default)]
 pub struct SystemSchedule {
     /// List of system node ids
@@ -58,6 +102,12 @@ pub struct SystemSchedule {
     pub(super) systems: Vec<SystemWithAccess>,
     /// Indexed by system node id
     pub(super) system_conditions: Vec<Vec<ConditionWithAccess>>,
+    /// Indexed by system node id
+    /// Number of systems that the system immediately depends on
+    pub(super) system_dependencies: Vec<usize>,
+    /// Indexed by system node id
+    /// List of systems that immediately depend on the system
+    pub(super) system_dependents: Vec<Vec<usize>>,
     /// Indexed by system node ids
     pub(super) sets_with_conditions_of_systems: Vec<FixedBitSet>,
     /// List of system set node ids
@@ -73,11 +123,59 @@ impl SystemSchedule {
             system_ids: Vec::new(),
             systems: Vec::new(),
             system_conditions: Vec::new(),
+            system_dependencies: Vec::new(),
+            system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             set_ids: Vec::new(),
             set_conditions: Vec::new(),
         }
     }
+
+    /// Returns the systems in topological order
+    pub fn systems(&self) -> &[SystemWithAccess] {
+        &self.systems
+    }
+
+    /// Returns the systems in topological order, to be run in place
+    pub fn systems_mut(&mut self) -> &mut [SystemWithAccess] {
+        &mut self.systems
+    }
+
+    /// Returns the run conditions of each system, indexed like [`SystemSchedule::systems`]
+    pub fn system_conditions(&self) -> &[Vec<ConditionWithAccess>] {
+        &self.system_conditions
+    }
+
+    /// Returns the run conditions of each system, to be evaluated in place
+    pub fn system_conditions_mut(&mut self) -> &mut [Vec<ConditionWithAccess>] {
+        &mut self.system_conditions
+    }
+
+    /// Returns the number of systems that each system immediately depends on
+    pub fn system_dependencies(&self) -> &[usize] {
+        &self.system_dependencies
+    }
+
+    /// Returns the indices of the systems that immediately depend on each system
+    pub fn system_dependents(&self) -> &[Vec<usize>] {
+        &self.system_dependents
+    }
+
+    /// Returns, for each system, the indices of the sets with conditions that contain it
+    pub fn sets_with_conditions_of_systems(&self) -> &[FixedBitSet] {
+        &self.sets_with_conditions_of_systems
+    }
+
+    /// Returns the run conditions of each system set, indexed by the bits of
+    /// [`SystemSchedule::sets_with_conditions_of_systems`]
+    pub fn set_conditions(&self) -> &[Vec<ConditionWithAccess>] {
+        &self.set_conditions
+    }
+
+    /// Returns the run conditions of each system set, to be evaluated in place
+    pub fn set_conditions_mut(&mut self) -> &mut [Vec<ConditionWithAccess>] {
+        &mut self.set_conditions
+    }
 }
 
 /// A special [`System`] that instructs the executor to call [`System::apply_deferred`] on the systems
@@ -3,7 +3,7 @@ use crate::{
     error::{ErrorContext, FeapError},
     world::World
 };
-use super::{ExecutorKind, SystemExecutor, SystemSchedule};
+use super::{ExecutorHooks, ExecutorKind, SystemExecutor, SystemSchedule};
 
 /// Runs the schedule using a single thread
 #[derive(Default)]
@@ -18,7 +18,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         todo!()
     }
 
-    fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World, skip_systems: Option<&FixedBitSet>, error_handler: fn(FeapError, ErrorContext)) {
+    fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World, skip_systems: Option<&FixedBitSet>, error_handler: fn(FeapError, ErrorContext), _hooks: Option<&mut dyn ExecutorHooks>) {
         todo!()
     }
 }
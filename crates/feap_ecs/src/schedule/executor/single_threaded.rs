@@ -1,4 +1,4 @@
-use super::{ExecutorKind, SystemExecutor, SystemSchedule};
+use super::{ExecutorHooks, ExecutorKind, SystemExecutor, SystemSchedule};
 use crate::{
     error::{ErrorContext, ErrorHandler, FeapError},
     schedule::node::ConditionWithAccess,
@@ -41,6 +41,7 @@ impl SystemExecutor for SingleThreadedExecutor {
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
         error_handler: fn(FeapError, ErrorContext),
+        mut hooks: Option<&mut dyn ExecutorHooks>,
     ) {
         // If stepping is enabled, make sure we skip those systems that should not be run
         #[cfg(feature = "feap_debug_stepping")]
@@ -86,6 +87,10 @@ impl SystemExecutor for SingleThreadedExecutor {
                 todo!()
             }
 
+            if let Some(hooks) = hooks.as_deref_mut() {
+                hooks.on_system_start(system);
+            }
+
             let f = AssertUnwindSafe(|| {
                 if let Err(RunSystemError::Failed(err)) =
                     super::__rust_begin_short_backtrace::run_without_applying_deferred(
@@ -96,17 +101,26 @@ impl SystemExecutor for SingleThreadedExecutor {
                 }
             });
 
-            #[cfg(feature = "std")]
-            #[expect(clippy::print_stderr, reason = "Allowed behind `std` feature gate.")]
-            {
-                if let Err(payload) = std::panic::catch_unwind(f) {
-                    std::eprintln!("Encountered a panic in system `{}`!", system.name());
-                    std::panic::resume_unwind(payload);
+            feap_core::cfg::switch! {
+                #[cfg(feature = "std")] => {
+                    #[expect(clippy::print_stderr, reason = "Allowed behind `std` feature gate.")]
+                    {
+                        if let Err(payload) = std::panic::catch_unwind(f) {
+                            std::eprintln!("Encountered a panic in system `{}`!", system.name());
+                            if let Some(hooks) = hooks.as_deref_mut() {
+                                hooks.on_system_end(system);
+                            }
+                            std::panic::resume_unwind(payload);
+                        }
+                    }
+                }
+                _ => {
+                    (f)();
                 }
             }
-            #[cfg(not(feature = "std"))]
-            {
-                (f)();
+
+            if let Some(hooks) = hooks.as_deref_mut() {
+                hooks.on_system_end(system);
             }
 
             self.unapplied_systems.insert(system_index);
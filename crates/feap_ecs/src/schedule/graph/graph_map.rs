@@ -27,6 +27,17 @@ pub enum Direction {
     Incoming = 1,
 }
 
+impl Direction {
+    /// Return the opposite `Direction`
+    #[inline]
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Outgoing => Self::Incoming,
+            Self::Incoming => Self::Outgoing,
+        }
+    }
+}
+
 /// A `Graph` with undirected edges of some [`GraphNodeId`] `N`
 ///
 /// For example, an edge between *1* and *2* is equivalent to an edge between *2* and *1*
@@ -124,6 +135,37 @@ impl<const DIRECTED: bool, N: GraphNodeId, S: BuildHasher> Graph<DIRECTED, N, S>
             .filter_map(move |(n, d)| (!DIRECTED || d == dir || n == a).then_some(n))
     }
 
+    /// Returns an iterator of all edges between `a` and its neighbors in the specified direction,
+    /// as `(from, to)` pairs
+    ///
+    /// For an undirected graph, `Incoming` yields `(neighbor, a)` and `Outgoing` yields `(a, neighbor)`
+    pub fn edges_directed(
+        &self,
+        a: N,
+        dir: Direction,
+    ) -> impl DoubleEndedIterator<Item = (N, N)> + '_ {
+        self.neighbors_directed(a, dir).map(move |b| match dir {
+            Direction::Outgoing => (a, b),
+            Direction::Incoming => (b, a),
+        })
+    }
+
+    /// Returns an iterator of all nodes without any edge in the specified direction
+    ///
+    /// `Incoming` yields the sources of the graph and `Outgoing` its sinks.
+    /// For an undirected graph, both yield the isolated nodes
+    pub fn externals(&self, dir: Direction) -> impl Iterator<Item = N> + '_ {
+        self.nodes
+            .iter()
+            .filter(move |&(&a, neigh)| {
+                !neigh.iter().copied().map(N::Adjacent::into).any(|(n, d)| {
+                    // self loops only have the Outgoing entry, but count in both directions
+                    !DIRECTED || d == dir || n == a
+                })
+            })
+            .map(|(&a, _)| a)
+    }
+
     /// Return an iterator over all edges of the graph with their weight in arbitrary order
     pub fn all_edges(&self) -> impl ExactSizeIterator<Item = (N, N)> + '_ {
         self.edges.iter().copied().map(N::Edge::into)
@@ -194,10 +236,33 @@ impl<const DIRECTED: bool, N: GraphNodeId, S: BuildHasher> Graph<DIRECTED, N, S>
                 Self::edge_key(succ, n)
             };
             // Remove all successor links
-            todo!()
+            self.remove_single_edge(succ, n, dir.opposite());
+            // Remove all edge values
+            self.edges.remove(&edge);
         }
     }
 
+    /// Remove the entry of `b` from the adjacency list of `a`, returning `true` if it was found
+    ///
+    /// Only the adjacency list of `a` is updated, the edge set is left untouched
+    fn remove_single_edge(&mut self, a: N, b: N, dir: Direction) -> bool {
+        let Some(sus) = self.nodes.get_mut(&a) else {
+            return false;
+        };
+
+        let Some(index) = sus
+            .iter()
+            .copied()
+            .map(N::Adjacent::into)
+            .position(|elt| (DIRECTED && elt == (b, dir)) || (!DIRECTED && elt.0 == b))
+        else {
+            return false;
+        };
+
+        sus.swap_remove(index);
+        true
+    }
+
     /// Add an edge connecting `a` and `b` to the graph
     /// For a directed graph, the edge is directed form `a` to `b`
     pub fn add_edge(&mut self, a: N, b: N) {
@@ -2,6 +2,9 @@ mod graph_map;
 mod schedule_graph;
 mod tarjan_scc;
 
+#[cfg(test)]
+mod tests;
+
 pub use graph_map::{DiGraph, Direction, GraphNodeId, UnGraph};
 pub use schedule_graph::ScheduleGraph;
 
@@ -25,6 +28,8 @@ pub struct GraphInfo {
     /// The sets that the node depends on (must run before or after)
     pub(crate) dependencies: Vec<Dependency>,
     pub(crate) ambiguous_with: Ambiguity,
+    /// Whether the node's [`SystemTypeSet`](super::SystemTypeSet) is replaced by an anonymous set
+    pub(crate) distinct: bool,
 }
 
 /// An edge to be added to the dependency graph
@@ -34,6 +39,16 @@ pub(crate) struct Dependency {
     pub(crate) options: TypeIdMap<Box<dyn Any>>,
 }
 
+impl Dependency {
+    pub(crate) fn new(kind: DependencyKind, set: InternedSystemSet) -> Self {
+        Self {
+            kind,
+            set,
+            options: Default::default(),
+        }
+    }
+}
+
 /// Specifies what kind of edge should be added to the dependency graph
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub(crate) enum DependencyKind {
@@ -67,6 +82,19 @@ impl<N: GraphNodeId> Default for Dag<N> {
     }
 }
 
+impl<N: GraphNodeId> Dag<N> {
+    /// The directed graph of the stored directed acyclic graph
+    pub fn graph(&self) -> &DiGraph<N> {
+        &self.graph
+    }
+
+    /// A cached topological ordering of the graph
+    /// This is only up to date after the schedule has been built
+    pub fn cached_topsort(&self) -> &[N] {
+        &self.topsort
+    }
+}
+
 /// Values returned by [`ScheduleGraph::process_config`]
 pub(super) struct ProcessConfigsResult {
     /// All nodes contained inside this `process_configs` call's [`ScheduleConfigs`] hierarchy,
@@ -84,6 +112,7 @@ pub(super) trait ProcessScheduleConfig: Schedulable + Sized {
 }
 
 impl ProcessScheduleConfig for ScheduleSystem {
+    #[track_caller]
     fn process_config(schedule_graph: &mut ScheduleGraph, config: ScheduleConfig<Self>) -> NodeId {
         NodeId::System(schedule_graph.add_system_inner(config))
     }
@@ -6,20 +6,23 @@ use crate::{
     component::ComponentId,
     schedule::{
         config::{Schedulable, ScheduleConfig, ScheduleConfigs}, error::{ScheduleBuildError, ScheduleBuildWarning}, executor::SystemSchedule, node::{NodeId, SystemKey, SystemSetKey, SystemSets, Systems}, pass::ScheduleBuildPassObj,
+        AnonymousSet,
         BoxedCondition,
         Chain,
         GraphInfo,
         InternedScheduleLabel,
         InternedSystemSet,
         IntoScheduleConfigs,
+        SystemSet,
     },
-    system::ScheduleSystem,
+    system::{ScheduleSystem, System},
     world::World,
 };
 use alloc::{
     boxed::Box,
     collections::{BTreeMap, BTreeSet},
-    string::String,
+    format,
+    string::{String, ToString},
     vec,
     vec::Vec,
 };
@@ -42,6 +45,8 @@ pub struct ScheduleGraph {
     /// Map of systems in each set
     set_systems: HashMap<SystemSetKey, Vec<SystemKey>>,
     ambiguous_with: UnGraph<NodeId>,
+    /// Number of anonymous sets created so far, used to give each a unique id
+    anonymous_sets: usize,
     conflicting_systems: Vec<(SystemKey, SystemKey, Vec<ComponentId>)>,
     pub(crate) changed: bool,
     settings: ScheduleBuildSettings,
@@ -58,6 +63,7 @@ impl ScheduleGraph {
             dependency: Dag::default(),
             set_systems: HashMap::default(),
             ambiguous_with: UnGraph::default(),
+            anonymous_sets: 0,
             conflicting_systems: Vec::new(),
             changed: false,
             settings: ScheduleBuildSettings::default(),
@@ -65,15 +71,106 @@ impl ScheduleGraph {
         }
     }
 
+    /// Returns the [`Dag`] of the hierarchy
+    /// The hierarchy is a directed acyclic graph of the systems and sets,
+    /// where an edge denotes that a system or set is the child of another set
+    pub fn hierarchy(&self) -> &Dag<NodeId> {
+        &self.hierarchy
+    }
+
+    /// Returns the [`Dag`] of the dependencies in the schedule
+    /// Nodes in this graph are systems and sets, and edges denote that a system or set
+    /// has to run before another system or set
+    pub fn dependency(&self) -> &Dag<NodeId> {
+        &self.dependency
+    }
+
     /// Returns the name of the node with the given [`NodeId`].
     /// Resolves anonymous sets to a string that describes their contents
     pub fn get_node_name(&self, id: &NodeId) -> String {
         self.get_node_name_inner(id, self.settings.report_sets)
     }
 
+    /// Returns the debug names of all systems and system sets in the graph, by [`NodeId`]
+    ///
+    /// Keys are stable across schedule rebuilds, so this map can be used to give names to
+    /// keys collected elsewhere, such as per-system statistics
+    pub fn key_names(&self) -> BTreeMap<NodeId, String> {
+        let systems = self
+            .systems
+            .names()
+            .map(|(key, name)| (NodeId::System(key), name.to_string()));
+        let sets = self
+            .system_sets
+            .iter()
+            .map(|(key, set)| (NodeId::Set(key), format!("{set:?}")));
+        systems.chain(sets).collect()
+    }
+
     #[inline]
     fn get_node_name_inner(&self, id: &NodeId, report_sets: bool) -> String {
-        todo!()
+        match *id {
+            NodeId::System(key) => {
+                let name = self
+                    .systems
+                    .name(key)
+                    .map_or_else(|| format!("{id}"), ToString::to_string);
+                if !report_sets {
+                    return name;
+                }
+                let sets = self.names_of_sets_containing_node(id);
+                match sets.len() {
+                    0 => name,
+                    1 => format!("{name} (in set {})", sets[0]),
+                    _ => format!("{name} (in sets {})", sets.join(", ")),
+                }
+            }
+            NodeId::Set(key) => match self.system_sets.get(key) {
+                Some(set) if set.is_anonymous() => self.anonymous_set_name(id),
+                Some(set) => format!("{set:?}"),
+                None => format!("{id}"),
+            },
+        }
+    }
+
+    /// Names an anonymous set after its members
+    fn anonymous_set_name(&self, id: &NodeId) -> String {
+        let members: Vec<String> = self
+            .hierarchy
+            .graph()
+            .neighbors_directed(*id, Direction::Outgoing)
+            .map(|member| self.get_node_name_inner(&member, false))
+            .collect();
+        format!("({})", members.join(", "))
+    }
+
+    /// Returns the sorted names of the named sets that contain the node, directly or through other sets
+    fn names_of_sets_containing_node(&self, id: &NodeId) -> Vec<String> {
+        let mut sets = BTreeSet::new();
+        self.traverse_sets_containing_node(*id, &mut |key| {
+            self.system_sets[key].system_type().is_none() && sets.insert(key)
+        });
+        let mut names: Vec<String> = sets
+            .into_iter()
+            .map(|key| self.get_node_name_inner(&NodeId::Set(key), false))
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Calls `f` on every set containing the node, walking up through a set when `f` returns `true`
+    fn traverse_sets_containing_node(&self, id: NodeId, f: &mut impl FnMut(SystemSetKey) -> bool) {
+        for parent in self
+            .hierarchy
+            .graph()
+            .neighbors_directed(id, Direction::Incoming)
+        {
+            if let NodeId::Set(key) = parent
+                && f(key)
+            {
+                self.traverse_sets_containing_node(parent, f);
+            }
+        }
     }
 
     #[track_caller]
@@ -185,6 +282,7 @@ impl ScheduleGraph {
         }
     }
 
+    #[track_caller]
     fn process_config<T: ProcessScheduleConfig + Schedulable>(
         &mut self,
         config: ScheduleConfig<T>,
@@ -212,6 +310,7 @@ impl ScheduleGraph {
     }
 
     /// Add a [`ScheduleConfig`] to the graph, including its dependencies and conditions
+    #[track_caller]
     pub(super) fn add_system_inner(&mut self, config: ScheduleConfig<ScheduleSystem>) -> SystemKey {
         let key = self.systems.insert(config.node, config.conditions);
 
@@ -221,6 +320,105 @@ impl ScheduleGraph {
         key
     }
 
+    /// Removes all systems that are part of `set`, directly or through its subsets,
+    /// and returns the number of removed systems
+    ///
+    /// The set itself stays in the graph. Systems that are currently moved into the
+    /// executable schedule have to be reclaimed with [`ScheduleGraph::reclaim_systems`] first
+    pub(crate) fn remove_systems_in_set(&mut self, set: InternedSystemSet) -> usize {
+        let Some(key) = self.system_sets.get_key(set) else {
+            return 0;
+        };
+
+        let mut systems = BTreeSet::new();
+        let mut visited = HashSet::<NodeId>::default();
+        let mut stack = vec![NodeId::Set(key)];
+        while let Some(id) = stack.pop() {
+            for child in self
+                .hierarchy
+                .graph
+                .neighbors_directed(id, Direction::Outgoing)
+            {
+                match child {
+                    NodeId::System(key) => {
+                        systems.insert(key);
+                    }
+                    NodeId::Set(_) => {
+                        if visited.insert(child) {
+                            stack.push(child);
+                        }
+                    }
+                }
+            }
+        }
+
+        for &key in &systems {
+            self.remove_system(key);
+        }
+        systems.len()
+    }
+
+    /// Removes all systems whose [`System::type_id`] is `type_id`, and returns the number
+    /// of removed systems
+    ///
+    /// Systems that are currently moved into the executable schedule have to be reclaimed with
+    /// [`ScheduleGraph::reclaim_systems`] first
+    pub(crate) fn remove_systems_by_type_id(&mut self, type_id: TypeId) -> usize {
+        let systems: Vec<SystemKey> = self
+            .systems
+            .iter()
+            .filter(|(_, system)| System::type_id(&***system) == type_id)
+            .map(|(key, _)| key)
+            .collect();
+
+        for &key in &systems {
+            self.remove_system(key);
+        }
+        systems.len()
+    }
+
+    /// Removes a single system from the graphs, keeping the ordering between the nodes it
+    /// depended on and the nodes that depended on it
+    fn remove_system(&mut self, key: SystemKey) {
+        let id = NodeId::System(key);
+        let before: Vec<NodeId> = self
+            .dependency
+            .graph
+            .neighbors_directed(id, Direction::Incoming)
+            .filter(|&node| node != id)
+            .collect();
+        let after: Vec<NodeId> = self
+            .dependency
+            .graph
+            .neighbors_directed(id, Direction::Outgoing)
+            .filter(|&node| node != id)
+            .collect();
+
+        self.hierarchy.graph.remove_node(id);
+        self.dependency.graph.remove_node(id);
+        self.ambiguous_with.remove_node(id);
+        for &a in &before {
+            for &b in &after {
+                self.dependency.graph.add_edge(a, b);
+            }
+        }
+
+        for systems in self.set_systems.values_mut() {
+            systems.retain(|&system| system != key);
+        }
+        self.conflicting_systems
+            .retain(|&(a, b, _)| a != key && b != key);
+        self.systems.remove(key);
+        self.changed = true;
+    }
+
+    /// Creates a new [`AnonymousSet`] that is unique within this schedule
+    fn create_anonymous_set(&mut self) -> AnonymousSet {
+        let id = self.anonymous_sets;
+        self.anonymous_sets += 1;
+        AnonymousSet::new(id)
+    }
+
     /// Update the internal graphs (hierarchy, dependency, ambiguity) by adding a single [`GraphInfo`]
     fn update_graphs(&mut self, id: NodeId, graph_info: GraphInfo) {
         self.changed = true;
@@ -229,16 +427,18 @@ impl ScheduleGraph {
             hierarchy: sets,
             dependencies,
             ambiguous_with,
-            ..
+            distinct,
         } = graph_info;
 
         self.hierarchy.graph.add_node(id);
         self.dependency.graph.add_node(id);
 
-        for key in sets
-            .into_iter()
-            .map(|set| self.system_sets.get_key_or_insert(set))
-        {
+        for mut set in sets {
+            // distinct systems leave the set shared by all instances of their function
+            if distinct && set.system_type().is_some() {
+                set = self.create_anonymous_set().intern();
+            }
+            let key = self.system_sets.get_key_or_insert(set);
             self.hierarchy.graph.add_edge(NodeId::Set(key), id);
 
             // ensure set also appears in dependency graph
@@ -297,7 +497,7 @@ impl ScheduleGraph {
         if let Some(warning) =
             self.optionally_check_hierarchy_conflicts(&hier_results.transitive_edges)?
         {
-            todo!()
+            warnings.push(warning);
         }
 
         // Remove redundant edges
@@ -450,8 +650,8 @@ impl ScheduleGraph {
             set_conditions: Vec::with_capacity(set_with_conditions_count),
             system_ids: dg_system_ids,
             set_ids: hg_set_ids,
-            // system_dependencies,
-            // system_dependents,
+            system_dependencies,
+            system_dependents,
             sets_with_conditions_of_systems,
             // systems_in_sets_with_conditions,
         }
@@ -514,17 +714,30 @@ impl ScheduleGraph {
                 todo!()
             }
             if systems.is_empty() {
-                todo!()
+                // collapse dependencies for empty sets
+                for a in
+                    dependency_flattening.neighbors_directed(NodeId::Set(set), Direction::Incoming)
+                {
+                    for b in dependency_flattening
+                        .neighbors_directed(NodeId::Set(set), Direction::Outgoing)
+                    {
+                        temp.push((a, b));
+                    }
+                }
             } else {
                 for a in
                     dependency_flattening.neighbors_directed(NodeId::Set(set), Direction::Incoming)
                 {
-                    todo!()
+                    for &sys in systems {
+                        temp.push((a, NodeId::System(sys)));
+                    }
                 }
                 for b in
                     dependency_flattening.neighbors_directed(NodeId::Set(set), Direction::Outgoing)
                 {
-                    todo!()
+                    for &sys in systems {
+                        temp.push((NodeId::System(sys), b));
+                    }
                 }
             }
 
@@ -571,26 +784,16 @@ impl ScheduleGraph {
         conflicting_systems
     }
 
-    /// Updates the `SystemSchedule` from the `ScheduleGraph`
-    pub(crate) fn update_schedule(
-        &mut self,
-        world: &mut World,
-        schedule: &mut SystemSchedule,
-        ignored_ambiguities: &BTreeSet<ComponentId>,
-        schedule_label: InternedScheduleLabel,
-    ) -> Result<Vec<ScheduleBuildWarning>, ScheduleBuildError> {
-        if !self.systems.is_initialized() || !self.system_sets.is_initialized() {
-            return Err(ScheduleBuildError::Uninitialized);
-        }
-
-        // Move systems out of old schedule
+    /// Moves the systems and conditions of `schedule` back into the graph, leaving it empty
+    pub(crate) fn reclaim_systems(&mut self, schedule: &mut SystemSchedule) {
         for ((key, system), conditions) in schedule
             .system_ids
             .drain(..)
             .zip(schedule.systems.drain(..))
             .zip(schedule.system_conditions.drain(..))
         {
-            todo!()
+            self.systems.node_mut(key).unwrap().inner = Some(system);
+            *self.systems.get_conditions_mut(key).unwrap() = conditions;
         }
 
         for (key, conditions) in schedule
@@ -598,8 +801,24 @@ impl ScheduleGraph {
             .drain(..)
             .zip(schedule.set_conditions.drain(..))
         {
-            todo!()
+            *self.system_sets.get_conditions_mut(key).unwrap() = conditions;
         }
+    }
+
+    /// Updates the `SystemSchedule` from the `ScheduleGraph`
+    pub(crate) fn update_schedule(
+        &mut self,
+        world: &mut World,
+        schedule: &mut SystemSchedule,
+        ignored_ambiguities: &BTreeSet<ComponentId>,
+        schedule_label: InternedScheduleLabel,
+    ) -> Result<Vec<ScheduleBuildWarning>, ScheduleBuildError> {
+        if !self.systems.is_initialized() || !self.system_sets.is_initialized() {
+            return Err(ScheduleBuildError::Uninitialized);
+        }
+
+        // Move systems out of old schedule
+        self.reclaim_systems(schedule);
 
         let (new_schedule, warnings) = self.build_schedule(world, ignored_ambiguities)?;
         *schedule = new_schedule;
@@ -621,7 +840,8 @@ impl ScheduleGraph {
         }
 
         for &key in &schedule.set_ids {
-            todo!()
+            let conditions = core::mem::take(self.system_sets.get_conditions_mut(key).unwrap());
+            schedule.set_conditions.push(conditions);
         }
 
         Ok(warnings)
@@ -710,7 +930,12 @@ impl ScheduleGraph {
                 continue;
             };
 
-            todo!()
+            let a_systems = set_system_sets.get(&a_key).unwrap();
+            let b_systems = set_system_sets.get(&b_key).unwrap();
+
+            if !a_systems.is_disjoint(b_systems) {
+                return Err(ScheduleBuildError::SetsHaveOrderButIntersect(a_key, b_key));
+            }
         }
 
         Ok(())
@@ -723,7 +948,26 @@ impl ScheduleGraph {
         for (&key, systems) in set_systems {
             let set = &self.system_sets[key];
             if set.system_type().is_some() {
-                todo!()
+                let instances = systems.len();
+                let id = NodeId::Set(key);
+                let relations = self
+                    .dependency
+                    .graph
+                    .neighbors_directed(id, Direction::Incoming)
+                    .count()
+                    + self
+                        .dependency
+                        .graph
+                        .neighbors_directed(id, Direction::Outgoing)
+                        .count()
+                    + self.ambiguous_with.neighbors(id).count();
+                if instances > 1 && relations > 0 {
+                    let locations = systems
+                        .iter()
+                        .filter_map(|&system| self.systems.location(system))
+                        .collect();
+                    return Err(ScheduleBuildError::SystemTypeSetAmbiguity(key, locations));
+                }
             }
         }
 
@@ -1,6 +1,6 @@
 use crate::schedule::graph::{DiGraph, GraphNodeId};
 use alloc::vec::Vec;
-use core::{hash::BuildHasher, num::NonZeroUsize};
+use core::{hash::BuildHasher, iter::Peekable, num::NonZeroUsize};
 use smallvec::SmallVec;
 
 /// Create an iterator over *strongly connected components* using Algorithm 3 in
@@ -26,7 +26,7 @@ pub(crate) fn new_tarjan_scc<N: GraphNodeId, S: BuildHasher>(
         .nodes()
         .map(|node| NodeData {
             root_index: None,
-            neighbors: graph.neighbors(node),
+            neighbors: graph.neighbors(node).peekable(),
         })
         .collect::<Vec<_>>();
 
@@ -45,7 +45,7 @@ pub(crate) fn new_tarjan_scc<N: GraphNodeId, S: BuildHasher>(
 
 struct NodeData<Neighbors: Iterator<Item: GraphNodeId>> {
     root_index: Option<NonZeroUsize>,
-    neighbors: Neighbors,
+    neighbors: Peekable<Neighbors>,
 }
 
 /// A state for computing the *strongly connected components* using [Tarjan's algorithm][1]
@@ -133,7 +133,9 @@ impl<'graph, N: GraphNodeId, S: BuildHasher, A: Iterator<Item = N>, Neighbors: I
             self.index += 1;
         }
 
-        while let Some(w) = self.nodes[self.graph.to_index(v)].neighbors.next() {
+        // Neighbors are only consumed once they have been visited, so that a neighbor visited
+        // through the visitation stack still updates the root index of `v` when it is revisited
+        while let Some(&w) = self.nodes[self.graph.to_index(v)].neighbors.peek() {
             // If a neighbor hasn't been visited yet...
             if self.nodes[self.graph.to_index(w)].root_index.is_none() {
                 // Push the current node and the neighbor back onto the visitation stack.
@@ -151,10 +153,13 @@ impl<'graph, N: GraphNodeId, S: BuildHasher, A: Iterator<Item = N>, Neighbors: I
                     self.nodes[self.graph.to_index(w)].root_index;
                 v_is_local_root = false;
             }
+            self.nodes[self.graph.to_index(v)].neighbors.next();
         }
 
         if !v_is_local_root {
-            todo!()
+            // The stack is filled up when backtracking, unlike in Tarjan's original algorithm
+            self.stack.push(v);
+            return None;
         }
 
         // Pop the stack and generate an SCC
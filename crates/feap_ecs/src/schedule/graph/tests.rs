@@ -0,0 +1,215 @@
+//! Property tests for the graph algorithms the schedule builder relies on
+
+#![expect(
+    clippy::needless_range_loop,
+    reason = "Node pairs are compared across several adjacency matrices"
+)]
+
+use super::{check_graph, index, DiGraph, Direction, GraphNodeId};
+use alloc::{vec, vec::Vec};
+use feap_core::collections::HashSet;
+use proptest::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct TestNode(usize);
+
+impl GraphNodeId for TestNode {
+    type Adjacent = (TestNode, Direction);
+    type Edge = (TestNode, TestNode);
+}
+
+const MAX_NODES: usize = 24;
+
+/// Builds a graph with `n` nodes inserted in order, so that `0..n` is a topological order
+/// whenever every edge goes from a lower to a higher index
+fn build_graph(n: usize, edges: &[(usize, usize)]) -> DiGraph<TestNode> {
+    let mut graph = DiGraph::default();
+    for node in 0..n {
+        graph.add_node(TestNode(node));
+    }
+    for &(a, b) in edges {
+        graph.add_edge(TestNode(a), TestNode(b));
+    }
+    graph
+}
+
+/// Strategy for a random DAG: edges only go from lower to higher node indices
+fn dag() -> impl Strategy<Value = (usize, Vec<(usize, usize)>)> {
+    (1..MAX_NODES).prop_flat_map(|n| {
+        let edges = prop::collection::vec((0..n, 0..n), 0..n * 3).prop_map(|pairs| {
+            pairs
+                .into_iter()
+                .filter(|(a, b)| a != b)
+                .map(|(a, b)| (a.min(b), a.max(b)))
+                .collect::<Vec<_>>()
+        });
+        (Just(n), edges)
+    })
+}
+
+/// Strategy for a random directed graph, possibly containing cycles and self-loops
+fn digraph() -> impl Strategy<Value = (usize, Vec<(usize, usize)>)> {
+    (1..MAX_NODES).prop_flat_map(|n| (Just(n), prop::collection::vec((0..n, 0..n), 0..n * 3)))
+}
+
+/// Naive reachability through at least one edge, by depth-first search from every node
+fn naive_reachability(graph: &DiGraph<TestNode>, n: usize) -> Vec<Vec<bool>> {
+    let mut reachable = vec![vec![false; n]; n];
+    for (start, row) in reachable.iter_mut().enumerate() {
+        let mut stack: Vec<TestNode> = graph.neighbors(TestNode(start)).collect();
+        while let Some(TestNode(node)) = stack.pop() {
+            if !row[node] {
+                row[node] = true;
+                stack.extend(graph.neighbors(TestNode(node)));
+            }
+        }
+    }
+    reachable
+}
+
+fn edge_set(graph: &DiGraph<TestNode>) -> HashSet<(TestNode, TestNode)> {
+    graph.all_edges().collect()
+}
+
+proptest! {
+    #[test]
+    fn transitive_reduction_and_closure_bound_the_graph((n, edges) in dag()) {
+        let graph = build_graph(n, &edges);
+        let topsort: Vec<_> = (0..n).map(TestNode).collect();
+        let results = check_graph(&graph, &topsort);
+
+        let graph_edges = edge_set(&graph);
+        let reduction = edge_set(&results.transitive_reduction);
+        let closure = edge_set(&results.transitive_closure);
+
+        prop_assert!(reduction.is_subset(&graph_edges));
+        prop_assert!(graph_edges.is_subset(&closure));
+        prop_assert_eq!(reduction.len() + results.transitive_edges.len(), graph_edges.len());
+        for edge in &results.transitive_edges {
+            prop_assert!(graph_edges.contains(edge));
+            prop_assert!(!reduction.contains(edge));
+        }
+    }
+
+    #[test]
+    fn reachability_partitions_node_pairs((n, edges) in dag()) {
+        let graph = build_graph(n, &edges);
+        let topsort: Vec<_> = (0..n).map(TestNode).collect();
+        let results = check_graph(&graph, &topsort);
+        let naive = naive_reachability(&graph, n);
+
+        prop_assert_eq!(results.connected.len() + results.disconnected.len(), n * (n - 1) / 2);
+        let disconnected: HashSet<_> = results.disconnected.iter().copied().collect();
+        for a in 0..n {
+            for b in (a + 1)..n {
+                let pair = (TestNode(a), TestNode(b));
+                prop_assert_eq!(results.reachable[index(a, b, n)], naive[a][b]);
+                prop_assert_eq!(results.connected.contains(&pair), naive[a][b]);
+                prop_assert_eq!(disconnected.contains(&pair), !naive[a][b]);
+                // The graph is acyclic, so nothing can reach an earlier node
+                prop_assert!(!naive[b][a]);
+            }
+        }
+
+        let closure = edge_set(&results.transitive_closure);
+        for a in 0..n {
+            for b in 0..n {
+                prop_assert_eq!(closure.contains(&(TestNode(a), TestNode(b))), naive[a][b]);
+            }
+        }
+    }
+
+    #[test]
+    fn tarjan_scc_matches_naive_components((n, edges) in digraph()) {
+        let graph = build_graph(n, &edges);
+        let naive = naive_reachability(&graph, n);
+
+        let sccs: Vec<Vec<usize>> = graph
+            .iter_sccs()
+            .map(|scc| {
+                let mut scc: Vec<_> = scc.into_iter().map(|TestNode(node)| node).collect();
+                scc.sort_unstable();
+                scc
+            })
+            .collect();
+
+        // Every node appears in exactly one component
+        let mut seen = vec![false; n];
+        for node in sccs.iter().flatten() {
+            prop_assert!(!seen[*node]);
+            seen[*node] = true;
+        }
+        prop_assert!(seen.iter().all(|&seen| seen));
+
+        // Two nodes share a component if and only if they reach each other
+        let mut component = vec![0; n];
+        for (i, scc) in sccs.iter().enumerate() {
+            for &node in scc {
+                component[node] = i;
+            }
+        }
+        for a in 0..n {
+            for b in 0..n {
+                if a != b {
+                    prop_assert_eq!(component[a] == component[b], naive[a][b] && naive[b][a]);
+                }
+            }
+        }
+
+        // Components are emitted in reverse topological order
+        for (a, b) in graph.all_edges() {
+            prop_assert!(component[a.0] >= component[b.0]);
+        }
+    }
+
+    #[test]
+    fn directed_edges_match_edge_list((n, edges) in digraph()) {
+        let graph = build_graph(n, &edges);
+        let graph_edges = edge_set(&graph);
+
+        for node in graph.nodes() {
+            let outgoing: HashSet<_> = graph.edges_directed(node, Direction::Outgoing).collect();
+            let incoming: HashSet<_> = graph.edges_directed(node, Direction::Incoming).collect();
+            let expected_outgoing: HashSet<_> =
+                graph_edges.iter().copied().filter(|&(a, _)| a == node).collect();
+            let expected_incoming: HashSet<_> =
+                graph_edges.iter().copied().filter(|&(_, b)| b == node).collect();
+            prop_assert_eq!(outgoing, expected_outgoing);
+            prop_assert_eq!(incoming, expected_incoming);
+        }
+
+        let sources: HashSet<_> = graph.externals(Direction::Incoming).collect();
+        let sinks: HashSet<_> = graph.externals(Direction::Outgoing).collect();
+        for node in graph.nodes() {
+            prop_assert_eq!(sources.contains(&node), !graph_edges.iter().any(|&(_, b)| b == node));
+            prop_assert_eq!(sinks.contains(&node), !graph_edges.iter().any(|&(a, _)| a == node));
+        }
+    }
+
+    #[test]
+    fn removing_a_node_matches_rebuilding_without_it((n, edges) in digraph(), removed in 0..MAX_NODES) {
+        let removed = removed % n;
+        let mut graph = build_graph(n, &edges);
+        graph.remove_node(TestNode(removed));
+
+        let mut expected = DiGraph::<TestNode>::default();
+        for node in (0..n).filter(|&node| node != removed) {
+            expected.add_node(TestNode(node));
+        }
+        for &(a, b) in edges.iter().filter(|&&(a, b)| a != removed && b != removed) {
+            expected.add_edge(TestNode(a), TestNode(b));
+        }
+
+        prop_assert!(!graph.nodes().any(|node| node == TestNode(removed)));
+        prop_assert_eq!(edge_set(&graph), edge_set(&expected));
+        for node in expected.nodes() {
+            let neighbors: HashSet<_> = graph.neighbors(node).collect();
+            let expected_neighbors: HashSet<_> = expected.neighbors(node).collect();
+            prop_assert_eq!(neighbors, expected_neighbors);
+            let incoming: HashSet<_> = graph.edges_directed(node, Direction::Incoming).collect();
+            let expected_incoming: HashSet<_> =
+                expected.edges_directed(node, Direction::Incoming).collect();
+            prop_assert_eq!(incoming, expected_incoming);
+        }
+    }
+}
@@ -10,18 +10,27 @@ mod graph;
 mod node;
 mod pass;
 mod schedule;
+mod schedule_test;
 mod set;
 
+#[cfg(test)]
+mod tests;
+
 pub use condition::BoxedCondition;
-pub use config::IntoScheduleConfigs;
-pub use executor::ExecutorKind;
+pub use config::{IntoScheduleConfigs, ScheduleConfigs};
+pub use error::{ScheduleBuildError, ScheduleBuildWarning};
+pub use executor::{ExecutorHooks, ExecutorKind, SystemExecutor, SystemSchedule};
 pub use feap_ecs_macros::ScheduleLabel;
 pub use graph::{GraphInfo, ScheduleGraph};
+pub use node::{ConditionWithAccess, SystemWithAccess};
 pub use schedule::*;
+pub use schedule_test::ScheduleTest;
 pub use set::*;
 
 use crate::{define_label, intern::Interned};
-use executor::{MultiThreadedExecutor, SingleThreadedExecutor, SystemExecutor};
+#[cfg(feature = "std")]
+use executor::MultiThreadedExecutor;
+use executor::SingleThreadedExecutor;
 
 pub type InternedScheduleLabel = Interned<dyn ScheduleLabel>;
 
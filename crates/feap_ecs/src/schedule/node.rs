@@ -3,19 +3,29 @@ use super::{
     SystemSet,
 };
 use crate::{
+    change_detection::MaybeLocation,
     query::FilteredAccessSet,
     system::{ReadOnlySystem, ScheduleSystem},
     world::World,
 };
 use alloc::{boxed::Box, vec::Vec};
-use core::{fmt::Debug, ops::Index};
+use core::{
+    fmt::{Debug, Display},
+    ops::Index,
+};
 use feap_core::collections::HashMap;
+use feap_utils::debug_info::DebugName;
 use slotmap::{new_key_type, Key, KeyData, SecondaryMap, SlotMap};
 
 new_key_type! {
     /// A unique identifier for a system in a [`ScheduleGraph`]
+    ///
+    /// A key keeps referring to the same system across schedule rebuilds, until the system
+    /// is removed from the graph
     pub struct SystemKey;
     /// A unique identifier for a system set in a [`ScheduleGraph`]
+    ///
+    /// Like [`SystemKey`], a set keeps its key across schedule rebuilds
     pub struct SystemSetKey;
 }
 
@@ -76,6 +86,26 @@ impl NodeId {
             NodeId::Set(set) => Some(*set),
         }
     }
+
+    /// Returns a short string naming the kind of the identified node
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            NodeId::System(_) => "system",
+            NodeId::Set(_) => "system set",
+        }
+    }
+}
+
+/// Displays the kind of the node and its key, such as `system 1v1`
+///
+/// Keys only depend on the insertion order of nodes, so the output is identical across runs
+impl Display for NodeId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NodeId::System(key) => write!(f, "{} {:?}", self.kind(), key.data()),
+            NodeId::Set(key) => write!(f, "{} {:?}", self.kind(), key.data()),
+        }
+    }
 }
 
 impl GraphNodeId for NodeId {
@@ -105,7 +135,8 @@ pub struct CompactNodeIdAndDirection {
 
 impl Debug for CompactNodeIdAndDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        todo!()
+        let tuple: (_, _) = (*self).into();
+        tuple.fmt(f)
     }
 }
 
@@ -147,7 +178,8 @@ pub struct CompactNodeIdPair {
 
 impl Debug for CompactNodeIdPair {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        todo!()
+        let tuple: (_, _) = (*self).into();
+        tuple.fmt(f)
     }
 }
 
@@ -192,13 +224,19 @@ impl From<CompactNodeIdPair> for (NodeId, NodeId) {
 /// A [`SystemWithAccess`] stored in a [`ScheduleGraph`]
 pub(crate) struct SystemNode {
     pub(crate) inner: Option<SystemWithAccess>,
+    /// The name of the system, kept while the system itself is moved into the executable schedule
+    pub(crate) name: DebugName,
+    /// Where the system was added to the schedule
+    pub(crate) location: MaybeLocation,
 }
 
 impl SystemNode {
     /// Creates a new [`SystemNode`]
-    pub fn new(system: ScheduleSystem) -> Self {
+    pub fn new(system: ScheduleSystem, location: MaybeLocation) -> Self {
         Self {
+            name: system.name(),
             inner: Some(SystemWithAccess::new(system)),
+            location,
         }
     }
 
@@ -269,12 +307,15 @@ impl Systems {
     ///
     /// We have to defer initialization of systems in the container until we have
     /// `&mut World` access, so we store these in a list.
+    #[track_caller]
     pub fn insert(
         &mut self,
         system: ScheduleSystem,
         conditions: Vec<Box<dyn ReadOnlySystem<In = (), Out = bool>>>,
     ) -> SystemKey {
-        let key = self.nodes.insert(SystemNode::new(system));
+        let key = self
+            .nodes
+            .insert(SystemNode::new(system, MaybeLocation::caller()));
         self.conditions.insert(
             key,
             conditions
@@ -307,6 +348,39 @@ impl Systems {
         self.uninit.is_empty()
     }
     
+    /// Returns where the system with the given key was added to the schedule
+    pub fn location(&self, key: SystemKey) -> Option<MaybeLocation> {
+        self.nodes.get(key).map(|node| node.location)
+    }
+
+    /// Returns the name of the system with the given key
+    ///
+    /// Unlike [`System::name`], this is available while the schedule is running
+    pub fn name(&self, key: SystemKey) -> Option<&DebugName> {
+        self.nodes.get(key).map(|node| &node.name)
+    }
+
+    /// Returns an iterator over the keys and names of all systems in this container
+    pub fn names(&self) -> impl Iterator<Item = (SystemKey, &DebugName)> {
+        self.nodes.iter().map(|(key, node)| (key, &node.name))
+    }
+
+    /// Returns an iterator over the keys of all systems in this container, along with the
+    /// systems that are not currently moved into the executable schedule
+    pub fn iter(&self) -> impl Iterator<Item = (SystemKey, &ScheduleSystem)> {
+        self.nodes
+            .iter()
+            .filter_map(|(key, node)| Some((key, &node.inner.as_ref()?.system)))
+    }
+
+    /// Removes the system with the given key and its conditions from this container,
+    /// returning `true` if it existed
+    pub(crate) fn remove(&mut self, key: SystemKey) -> bool {
+        self.uninit.retain(|&uninit| uninit != key);
+        self.conditions.remove(key);
+        self.nodes.remove(key).is_some()
+    }
+
     /// Returns a mutable reference to the system with the given key.
     pub(crate) fn node_mut(&mut self, key: SystemKey) -> Option<&mut SystemNode> {
         self.nodes.get_mut(key)
@@ -360,6 +434,24 @@ impl SystemSets {
         self.sets.get(key).map(|set| &**set)
     }
 
+    /// Returns an iterator over the keys and system sets in this container
+    pub fn iter(&self) -> impl Iterator<Item = (SystemSetKey, &dyn SystemSet)> {
+        self.sets.iter().map(|(key, set)| (key, &**set))
+    }
+
+    /// Returns a mutable reference to the conditions for the system set with the given key
+    pub fn get_conditions_mut(
+        &mut self,
+        key: SystemSetKey,
+    ) -> Option<&mut Vec<ConditionWithAccess>> {
+        self.conditions.get_mut(key)
+    }
+
+    /// Returns the key for the given system set, if it was added to this container
+    pub fn get_key(&self, set: InternedSystemSet) -> Option<SystemSetKey> {
+        self.ids.get(&set).copied()
+    }
+
     /// Returns the key for the given system set, inserting it into this
     /// container if it does not already exist
     pub fn get_key_or_insert(&mut self, set: InternedSystemSet) -> SystemSetKey {
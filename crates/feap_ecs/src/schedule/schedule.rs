@@ -1,13 +1,31 @@
 use super::{
-    error::{ScheduleBuildError, ScheduleBuildWarning}, executor::SystemSchedule, ExecutorKind, InternedScheduleLabel,
-    InternedSystemSet, IntoScheduleConfigs, MultiThreadedExecutor, ScheduleGraph, ScheduleLabel,
+    error::{ScheduleBuildError, ScheduleBuildWarning}, executor::SystemSchedule, graph::Direction,
+    node::{ConditionWithAccess, NodeId, SystemWithAccess}, ExecutorHooks, ExecutorKind,
+    InternedScheduleLabel,
+    InternedSystemSet, IntoScheduleConfigs, ScheduleGraph, ScheduleLabel,
     SingleThreadedExecutor,
     SystemExecutor,
+    SystemSet,
 };
+#[cfg(feature = "std")]
+use super::MultiThreadedExecutor;
 use crate::component::CheckChangeTicks;
-use crate::{component::ComponentId, resource::Resource, system::ScheduleSystem, world::World};
-use alloc::{boxed::Box, collections::BTreeSet, vec::Vec};
-use core::any::Any;
+use crate::{
+    component::ComponentId, error::ErrorContext, resource::Resource,
+    system::{IntoSystem, ScheduleSystem},
+    world::World,
+};
+use alloc::{
+    boxed::Box,
+    collections::BTreeSet,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    any::{Any, TypeId},
+    fmt::Write,
+};
 use feap_core::collections::HashMap;
 use feap_utils::map::TypeIdMap;
 
@@ -24,6 +42,7 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    executor_hooks: Option<Box<dyn ExecutorHooks>>,
     warnings: Vec<ScheduleBuildWarning>,
 }
 
@@ -36,6 +55,7 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            executor_hooks: None,
             warnings: Vec::new(),
         }
     }
@@ -45,6 +65,11 @@ impl Schedule {
         &self.graph
     }
 
+    /// Returns the executable schedule built by the last [`Schedule::initialize`]
+    pub(super) fn executable(&self) -> &SystemSchedule {
+        &self.executable
+    }
+
     /// Sets the schedule's execution strategy
     pub fn set_executor_kind(&mut self, executor: ExecutorKind) -> &mut Self {
         if executor != self.executor.kind() {
@@ -54,7 +79,29 @@ impl Schedule {
         self
     }
 
+    /// Replaces the schedule's executor with a custom [`SystemExecutor`]
+    ///
+    /// [`Schedule::set_executor_kind`] only replaces a custom executor with a built-in one
+    /// if the requested kind differs from the one reported by [`SystemExecutor::kind`]
+    pub fn set_executor(&mut self, executor: impl SystemExecutor) -> &mut Self {
+        self.executor = Box::new(executor);
+        self.executor_initialized = false;
+        self
+    }
+
+    /// Sets the [`ExecutorHooks`] called while this schedule runs, replacing any previous ones
+    pub fn set_executor_hooks(&mut self, hooks: impl ExecutorHooks) -> &mut Self {
+        self.executor_hooks = Some(Box::new(hooks));
+        self
+    }
+
+    /// Removes the [`ExecutorHooks`] of this schedule, returning them if they were set
+    pub fn take_executor_hooks(&mut self) -> Option<Box<dyn ExecutorHooks>> {
+        self.executor_hooks.take()
+    }
+
     /// Add a collection of systems to the schedule
+    #[track_caller]
     pub fn add_systems<M>(
         &mut self,
         systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
@@ -73,6 +120,36 @@ impl Schedule {
         self
     }
 
+    /// Removes all systems in `set`, directly or through its subsets, and returns the number of
+    /// removed systems
+    ///
+    /// Ordering constraints that went through a removed system are kept between its neighbors.
+    /// The schedule is rebuilt on its next run
+    pub fn remove_systems_in_set(&mut self, set: impl SystemSet) -> usize {
+        self.reclaim_systems();
+        self.graph.remove_systems_in_set(set.intern())
+    }
+
+    /// Removes all instances of `system` and returns the number of removed systems
+    ///
+    /// Functions and systems can be passed directly, every system of the same type is removed.
+    /// Ordering constraints that went through a removed system are kept between its neighbors.
+    /// The schedule is rebuilt on its next run
+    pub fn remove_systems_by_type<M, S: IntoSystem<(), (), M>>(&mut self, _system: S) -> usize {
+        self.remove_systems_by_type_id(TypeId::of::<S::System>())
+    }
+
+    fn remove_systems_by_type_id(&mut self, type_id: TypeId) -> usize {
+        self.reclaim_systems();
+        self.graph.remove_systems_by_type_id(type_id)
+    }
+
+    /// Moves the systems of the executable schedule back into the graph and marks it for a rebuild
+    fn reclaim_systems(&mut self) {
+        self.graph.reclaim_systems(&mut self.executable);
+        self.graph.changed = true;
+    }
+
     /// Runs all systems in this schedule on the `world`, using its current execution strategy
     pub fn run(&mut self, world: &mut World) {
         #[cfg(feature = "trace")]
@@ -89,9 +166,18 @@ impl Schedule {
 
         let error_handler = world.default_error_handler();
 
+        if let Some(hooks) = self.executor_hooks.as_deref_mut() {
+            hooks.on_schedule_start(self.label);
+        }
+
         #[cfg(not(feature = "feap_debug_stepping"))]
-        self.executor
-            .run(&mut self.executable, world, None, error_handler);
+        self.executor.run(
+            &mut self.executable,
+            world,
+            None,
+            error_handler,
+            self.executor_hooks.as_deref_mut(),
+        );
 
         #[cfg(feature = "feap_debug_stepping")]
         todo!()
@@ -125,19 +211,155 @@ impl Schedule {
         Ok(())
     }
 
+    /// Builds and initializes the schedule ahead of its first run, if it changed since the last build
+    ///
+    /// This lets apps pay the graph build cost during startup instead of on the first
+    /// [`Schedule::run`]. Warnings that have not been reported yet, including the ones left by an
+    /// earlier [`Schedule::initialize`], are passed to the world's
+    /// [`DefaultErrorHandler`](crate::error::DefaultErrorHandler), which panics by default
+    pub fn initialize_in_place(&mut self, world: &mut World) -> Result<(), ScheduleBuildError> {
+        self.initialize(world)?;
+        if !self.warnings.is_empty() {
+            let error_handler = world.default_error_handler();
+            for warning in self.warnings.drain(..) {
+                error_handler(
+                    warning.to_string(&self.graph, world).into(),
+                    ErrorContext::Schedule { label: self.label },
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the warnings produced by the last build of the schedule that have not been reported yet
+    pub fn warnings(&self) -> &[ScheduleBuildWarning] {
+        &self.warnings
+    }
+
+    /// Returns a human-readable tree of the schedule: system sets, their subsets and systems,
+    /// annotated with run conditions and ordering edges
+    ///
+    /// The schedule is initialized first, so that the report reflects the built schedule.
+    /// Sets that are automatically created for each system function are not shown
+    pub fn debug_report(&mut self, world: &mut World) -> Result<String, ScheduleBuildError> {
+        self.initialize(world)?;
+
+        let hierarchy = self.graph.hierarchy().graph();
+        let mut report = String::new();
+        let _ = writeln!(report, "{:?}", self.label);
+        for node in hierarchy.nodes() {
+            if !self.is_report_hidden(node) && self.report_parents(node).next().is_none() {
+                self.write_report_node(&mut report, node, 1);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Returns `true` for the automatically populated [`SystemTypeSet`]s
+    fn is_report_hidden(&self, node: NodeId) -> bool {
+        node.as_set().is_some_and(|key| {
+            self.graph
+                .system_sets
+                .get(key)
+                .is_some_and(|set| set.system_type().is_some())
+        })
+    }
+
+    /// Returns the visible parent sets of `node` in the hierarchy
+    fn report_parents(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.graph
+            .hierarchy()
+            .graph()
+            .neighbors_directed(node, Direction::Incoming)
+            .filter(move |&parent| parent != node && !self.is_report_hidden(parent))
+    }
+
+    fn write_report_node(&self, report: &mut String, node: NodeId, depth: usize) {
+        let _ = write!(
+            report,
+            "{:indent$}{} {}",
+            "",
+            if node.is_system() { "-" } else { "+" },
+            self.report_node_name(node),
+            indent = depth * 2
+        );
+
+        let conditions = self.report_condition_count(node);
+        if conditions > 0 {
+            let _ = write!(report, " [{conditions} condition(s)]");
+        }
+
+        let after: Vec<String> = self
+            .graph
+            .dependency()
+            .graph()
+            .neighbors_directed(node, Direction::Incoming)
+            .filter(|&before| before != node)
+            .map(|before| self.report_node_name(before))
+            .collect();
+        if !after.is_empty() {
+            let _ = write!(report, " (after: {})", after.join(", "));
+        }
+        let _ = writeln!(report);
+
+        for child in self
+            .graph
+            .hierarchy()
+            .graph()
+            .neighbors_directed(node, Direction::Outgoing)
+            .filter(|&child| child != node)
+        {
+            self.write_report_node(report, child, depth + 1);
+        }
+    }
+
+    fn report_node_name(&self, node: NodeId) -> String {
+        match node {
+            NodeId::System(key) => self
+                .executable
+                .system_ids
+                .iter()
+                .position(|&id| id == key)
+                .map(|index| self.executable.systems[index].system.name().to_string())
+                .unwrap_or_else(|| format!("{key:?}")),
+            NodeId::Set(key) => format!("{:?}", &self.graph.system_sets[key]),
+        }
+    }
+
+    fn report_condition_count(&self, node: NodeId) -> usize {
+        match node {
+            NodeId::System(key) => self
+                .executable
+                .system_ids
+                .iter()
+                .position(|&id| id == key)
+                .map_or(0, |index| self.executable.system_conditions[index].len()),
+            NodeId::Set(key) => self
+                .executable
+                .set_ids
+                .iter()
+                .position(|&id| id == key)
+                .map_or(0, |index| self.executable.set_conditions[index].len()),
+        }
+    }
+
     /// Iterates the change ticks of all systems in the schedule and clamps any older than
     /// [`MAX_CHANGE_AGE`]
     pub fn check_change_ticks(&mut self, check: CheckChangeTicks) {
-        for system in &mut self.executable.systems {
-            todo!()
+        for SystemWithAccess { system, .. } in &mut self.executable.systems {
+            system.check_change_tick(check);
         }
 
         for conditions in &mut self.executable.system_conditions {
-            todo!()
+            for ConditionWithAccess { condition, .. } in conditions {
+                condition.check_change_tick(check);
+            }
         }
 
         for conditions in &mut self.executable.set_conditions {
-            todo!()
+            for ConditionWithAccess { condition, .. } in conditions {
+                condition.check_change_tick(check);
+            }
         }
     }
 }
@@ -169,6 +391,11 @@ impl Schedules {
         self.inner.remove(&label.intern())
     }
 
+    /// Returns an iterator over the labels of all stored schedules
+    pub fn labels(&self) -> impl Iterator<Item = InternedScheduleLabel> + '_ {
+        self.inner.keys().copied()
+    }
+
     /// a mutable reference to the schedules associated with `label`, creating one if it doesn't exist
     pub fn entry(&mut self, label: impl ScheduleLabel) -> &mut Schedule {
         self.inner
@@ -177,6 +404,7 @@ impl Schedules {
     }
 
     /// Adds one or more systems to the [`Schedule`] matching the provided [`ScheduleLabel`]
+    #[track_caller]
     pub fn add_systems<M>(
         &mut self,
         schedule: impl ScheduleLabel,
@@ -197,6 +425,16 @@ impl Schedules {
         self
     }
 
+    /// Removes all instances of `system` from every stored schedule and returns the number of
+    /// removed systems
+    pub fn remove_systems_by_type<M, S: IntoSystem<(), (), M>>(&mut self, _system: S) -> usize {
+        let type_id = TypeId::of::<S::System>();
+        self.inner
+            .values_mut()
+            .map(|schedule| schedule.remove_systems_by_type_id(type_id))
+            .sum()
+    }
+
     /// Iterates the change ticks of all systems in all stored schedules and clamps any older than
     /// [`MAX_CHANGE_AGE`]
     pub(crate) fn check_change_ticks(&mut self, check: CheckChangeTicks) {
@@ -0,0 +1,183 @@
+use super::{Schedule, ScheduleLabel};
+use crate::{
+    system::{IntoSystem, System},
+    world::World,
+};
+use alloc::{string::ToString, vec, vec::Vec};
+
+/// A test utility that builds a [`Schedule`] without running it and checks the order of its systems
+///
+/// Systems are looked up either by type, using the function or system that was added, or by name.
+/// Ordering is checked against the dependency graph of the built schedule, so a regression in
+/// ordering constraints is caught without executing any system.
+///
+/// ```ignore
+/// let mut schedule = Schedule::new(Update);
+/// schedule.add_systems((spawn, movement).chain());
+///
+/// ScheduleTest::new(schedule).assert_order(spawn, movement);
+/// ```
+pub struct ScheduleTest {
+    schedule: Schedule,
+    world: World,
+    built: bool,
+}
+
+impl ScheduleTest {
+    /// Creates a test for `schedule`, which is built in an empty [`World`]
+    pub fn new(schedule: Schedule) -> Self {
+        Self::with_world(schedule, World::new())
+    }
+
+    /// Creates a test for `schedule`, which is built in `world`
+    pub fn with_world(schedule: Schedule, world: World) -> Self {
+        Self {
+            schedule,
+            world,
+            built: false,
+        }
+    }
+
+    /// Creates a test for an empty schedule with the given `label`
+    pub fn empty(label: impl ScheduleLabel) -> Self {
+        Self::new(Schedule::new(label))
+    }
+
+    /// Returns a mutable reference to the tested [`Schedule`], to add systems to it
+    ///
+    /// The schedule is rebuilt before the next assertion
+    pub fn schedule_mut(&mut self) -> &mut Schedule {
+        self.built = false;
+        &mut self.schedule
+    }
+
+    /// Returns a mutable reference to the [`World`] the schedule is built in
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Consumes the test, returning the schedule and the world it was built in
+    pub fn into_inner(self) -> (Schedule, World) {
+        (self.schedule, self.world)
+    }
+
+    /// Builds the schedule if it changed since the last assertion
+    ///
+    /// Panics if the schedule fails to build
+    #[track_caller]
+    pub fn build(&mut self) -> &mut Self {
+        if !self.built {
+            if let Err(error) = self.schedule.initialize(&mut self.world) {
+                panic!("schedule failed to build: {error}");
+            }
+            self.built = true;
+        }
+        self
+    }
+
+    /// Asserts that the system `before` is ordered to run before the system `after`,
+    /// directly or through other systems and sets
+    ///
+    /// Panics if either system was not added to the schedule, or was added more than once
+    #[track_caller]
+    pub fn assert_order<MA, MB>(
+        &mut self,
+        before: impl IntoSystem<(), (), MA>,
+        after: impl IntoSystem<(), (), MB>,
+    ) -> &mut Self {
+        let before = IntoSystem::into_system(before);
+        let after = IntoSystem::into_system(after);
+        self.build();
+        let from = self.find_system(&before.name().to_string(), |system| {
+            system.type_id() == System::type_id(&before)
+        });
+        let to = self.find_system(&after.name().to_string(), |system| {
+            system.type_id() == System::type_id(&after)
+        });
+        assert!(
+            self.has_path(from, to),
+            "expected `{}` to run before `{}`",
+            before.name(),
+            after.name()
+        );
+        self
+    }
+
+    /// Asserts that the system named `before` is ordered to run before the system named `after`,
+    /// directly or through other systems and sets
+    ///
+    /// Names are matched against [`System::name`], either in full or by their last path segment
+    /// and are only available with the `debug` feature
+    ///
+    /// Panics if the `debug` feature is disabled
+    #[track_caller]
+    pub fn assert_order_by_name(&mut self, before: &str, after: &str) -> &mut Self {
+        #[cfg(not(feature = "debug"))]
+        panic!(
+            "cannot order `{before}` before `{after}` by name: system names need the `debug` \
+            feature of `feap_ecs`, enable the `debug` feature or use `ScheduleTest::assert_order`"
+        );
+        #[cfg(feature = "debug")]
+        {
+            self.build();
+            let from = self.find_system(before, |system| name_matches(system, before));
+            let to = self.find_system(after, |system| name_matches(system, after));
+            assert!(
+                self.has_path(from, to),
+                "expected `{before}` to run before `{after}`"
+            );
+            self
+        }
+    }
+
+    /// Returns the index of the single system in the built schedule matching `predicate`
+    #[track_caller]
+    fn find_system(
+        &self,
+        name: &str,
+        predicate: impl Fn(&dyn System<In = (), Out = ()>) -> bool,
+    ) -> usize {
+        let matches: Vec<usize> = self
+            .schedule
+            .executable()
+            .systems
+            .iter()
+            .enumerate()
+            .filter(|(_, system)| predicate(&*system.system))
+            .map(|(index, _)| index)
+            .collect();
+        match matches.as_slice() {
+            [index] => *index,
+            [] => panic!("system `{name}` is not part of the schedule"),
+            _ => panic!("system `{name}` was added to the schedule more than once"),
+        }
+    }
+
+    /// Returns `true` if the system at index `to` depends on the system at index `from`
+    fn has_path(&self, from: usize, to: usize) -> bool {
+        let dependents = &self.schedule.executable().system_dependents;
+        let mut visited = vec![false; dependents.len()];
+        let mut stack = vec![from];
+        while let Some(index) = stack.pop() {
+            for &dependent in &dependents[index] {
+                if dependent == to {
+                    return true;
+                }
+                if !visited[dependent] {
+                    visited[dependent] = true;
+                    stack.push(dependent);
+                }
+            }
+        }
+        false
+    }
+}
+
+#[cfg(feature = "debug")]
+fn name_matches(system: &dyn System<In = (), Out = ()>, name: &str) -> bool {
+    let full = system.name().to_string();
+    full == name
+        || full
+            .strip_suffix(name)
+            .is_some_and(|prefix| prefix.ends_with("::"))
+}
@@ -1,9 +1,15 @@
-use super::ScheduleLabel;
-use crate::{define_label, intern::Interned};
-use alloc::boxed::Box;
-use core::{any::TypeId, fmt::Debug, hash::Hash, marker::PhantomData};
+use super::{Chain, IntoScheduleConfigs, ScheduleConfigs, ScheduleLabel};
+use crate::{define_label, intern::Interned, system::IntoSystem};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    any::TypeId,
+    fmt::{Debug, Display},
+    hash::Hash,
+    marker::PhantomData,
+};
 pub use feap_ecs_macros::SystemSet;
-use std::hash::Hasher;
+use core::hash::Hasher;
+use feap_utils::debug_info::DebugName;
 
 define_label!(
     /// System sets are tag-like labels that can be used to group systems together
@@ -21,16 +27,110 @@ define_label!(
         fn system_type(&self) -> Option<TypeId> {
             None
         }
+
+        /// Returns `true` if this system set is an [`AnonymousSet`]
+        fn is_anonymous(&self) -> bool {
+            false
+        }
     },
     extra_methods_impl: {
         fn system_type(&self) -> Option<TypeId> {
             (**self).system_type()
         }
+
+        fn is_anonymous(&self) -> bool {
+            (**self).is_anonymous()
+        }
     }
 );
 
 /// A shorthand for `Interned<dyn SystemSet>`
 pub type InternedSystemSet = Interned<dyn SystemSet>;
+
+/// A [`SystemSet`] enum whose variants can be listed, to configure all of them in a loop
+///
+/// Implemented by `#[derive(SystemSet)]` for enums without generics whose variants have no fields
+///
+/// ```ignore
+/// for set in GameSet::variants() {
+///     schedule.configure_sets(set.in_set(GameSystems));
+/// }
+///
+/// // Or order every variant after the previous one
+/// schedule.configure_sets(GameSet::chained());
+/// ```
+pub trait SystemSetVariants: SystemSet + Clone {
+    /// Every variant of the set, in declaration order
+    const VARIANTS: &'static [Self];
+
+    /// Returns an iterator over every variant of the set, in declaration order
+    fn variants() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+
+    /// Returns a configuration ordering every variant after the previous one, in declaration order
+    fn chained() -> ScheduleConfigs<InternedSystemSet> {
+        ScheduleConfigs::Configs {
+            configs: Self::variants().map(IntoScheduleConfigs::into_configs).collect(),
+            collective_conditions: Vec::new(),
+            metadata: Chain::Unchained,
+        }
+        .chain()
+    }
+}
+
+/// Declares a [`SystemSet`] enum whose variants run one after the other, like the phases of a
+/// pipeline
+///
+/// The enum derives [`SystemSet`] along with the traits it requires, and gets a `configs` function
+/// returning the configuration that chains its variants in declaration order.
+/// With `in Parent`, every variant is also added to the `Parent` set.
+///
+/// ```ignore
+/// phase_sets! {
+///     /// The phases of a frame of the game
+///     pub enum GamePhase in GameSystems {
+///         Input,
+///         Simulation,
+///         Presentation,
+///     }
+/// }
+///
+/// app.configure_sets(Update, GamePhase::configs());
+/// ```
+#[macro_export]
+macro_rules! phase_sets {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident $(in $parent:path)? {
+            $($(#[$variant_attr:meta])* $variant:ident),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Hash, PartialEq, Eq, Copy, Clone, $crate::schedule::SystemSet)]
+        $vis enum $name {
+            $($(#[$variant_attr])* $variant,)+
+        }
+
+        impl $name {
+            /// Returns the configuration chaining every phase in declaration order
+            $vis fn configs() -> $crate::schedule::ScheduleConfigs<$crate::schedule::InternedSystemSet> {
+                let configs = <Self as $crate::schedule::SystemSetVariants>::chained();
+                $(let configs = $crate::schedule::IntoScheduleConfigs::in_set(configs, $parent);)?
+                configs
+            }
+        }
+    };
+}
+
+/// Displays the set through its [`Debug`] output, which only depends on the set's type and
+/// fields, so it is identical across runs
+impl Display for InternedSystemSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
 /// A shorthand for `Interned<dyn ScheduleLabel>`
 pub type InternedScheduleLabel = Interned<dyn ScheduleLabel>;
 
@@ -50,7 +150,9 @@ impl<T: 'static> SystemTypeSet<T> {
 
 impl<T> Debug for SystemTypeSet<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        todo!()
+        f.debug_tuple("SystemTypeSet")
+            .field(&format_args!("fn {}()", DebugName::type_name::<T>()))
+            .finish()
     }
 }
 
@@ -79,6 +181,74 @@ impl<T> PartialEq for SystemTypeSet<T> {
 impl<T> Eq for SystemTypeSet<T> {}
 
 impl<T> SystemSet for SystemTypeSet<T> {
+    fn system_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<T>())
+    }
+
+    fn dyn_clone(&self) -> Box<dyn SystemSet> {
+        Box::new(*self)
+    }
+}
+
+/// Types that can be converted into a [`SystemSet`]
+///
+/// Functions and systems convert into the [`SystemTypeSet`] shared by all their instances
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a system set",
+    label = "invalid system set"
+)]
+pub trait IntoSystemSet<Marker>: Sized {
+    /// The type of [`SystemSet`] this instance converts into
+    type Set: SystemSet;
+
+    /// Converts this instance to its associated [`SystemSet`] type
+    fn into_system_set(self) -> Self::Set;
+}
+
+impl<S: SystemSet> IntoSystemSet<()> for S {
+    type Set = Self;
+
+    #[inline]
+    fn into_system_set(self) -> Self::Set {
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct SystemTypeSetMarker;
+
+impl<Marker, F> IntoSystemSet<(SystemTypeSetMarker, Marker)> for F
+where
+    F: IntoSystem<(), (), Marker>,
+{
+    type Set = SystemTypeSet<F::System>;
+
+    #[inline]
+    fn into_system_set(self) -> Self::Set {
+        SystemTypeSet::new()
+    }
+}
+
+/// A [`SystemSet`] implicitly created for a single node, with no name
+///
+/// Used by [`IntoScheduleConfigs::distinct`] to give a system its own set in place of the
+/// [`SystemTypeSet`] shared by all instances of its function
+///
+/// [`IntoScheduleConfigs::distinct`]: super::IntoScheduleConfigs::distinct
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnonymousSet(usize);
+
+impl AnonymousSet {
+    pub(crate) fn new(id: usize) -> Self {
+        Self(id)
+    }
+}
+
+impl SystemSet for AnonymousSet {
+    fn is_anonymous(&self) -> bool {
+        true
+    }
+
     fn dyn_clone(&self) -> Box<dyn SystemSet> {
         Box::new(*self)
     }
@@ -0,0 +1,691 @@
+//! Tests for building and running [`Schedule`]s
+
+use super::{
+    ExecutorHooks, ExecutorKind, InternedScheduleLabel, InternedSystemSet, IntoScheduleConfigs,
+    Schedule, ScheduleBuildError, ScheduleBuildWarning, ScheduleConfigs, ScheduleLabel,
+    ScheduleTest, Schedules, SystemExecutor, SystemSchedule, SystemSet, SystemSetVariants,
+    graph::Direction,
+    node::{CompactNodeIdAndDirection, CompactNodeIdPair, NodeId, SystemKey, SystemSetKey},
+};
+use crate::{
+    component::CheckChangeTicks,
+    error::{self, DefaultErrorHandler, ErrorContext, FeapError},
+    query::FilteredAccessSet,
+    resource::Resource,
+    system::{
+        IntoSystem, ReadOnlySystem, RunSystemError, ScheduleSystem, System,
+        SystemParamValidationError,
+    },
+    world::{UnsafeWorldCell, World},
+};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{hash::BuildHasher, marker::PhantomData};
+use feap_core::{collections::HashMap, hash::FixedHasher, sync::RwLock};
+use feap_utils::debug_info::DebugName;
+use fixedbitset::FixedBitSet;
+use slotmap::KeyData;
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct TestSchedule;
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct OtherSchedule;
+
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+struct Parent;
+
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+struct Child;
+
+fn system(_world: &mut World) {}
+
+#[derive(Resource, Default)]
+struct Log(Vec<&'static str>);
+
+fn a(world: &mut World) {
+    world.resource_mut::<Log>().0.push("a");
+}
+
+fn b(world: &mut World) {
+    world.resource_mut::<Log>().0.push("b");
+}
+
+fn c(world: &mut World) {
+    world.resource_mut::<Log>().0.push("c");
+}
+
+/// Runs `schedule` once and returns the names of the systems in the order they ran
+fn run_log(mut schedule: Schedule) -> Vec<&'static str> {
+    let mut world = World::new();
+    world.init_resource::<Log>();
+    schedule.run(&mut world);
+    core::mem::take(&mut world.resource_mut::<Log>().0)
+}
+
+/// Builds a schedule whose system is in both `Parent` and `Child`, while `Child` is already in
+/// `Parent`
+fn redundant_hierarchy() -> Schedule {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.configure_sets(Child.in_set(Parent));
+    schedule.add_systems(system.in_set(Child).in_set(Parent));
+    schedule
+}
+
+#[test]
+fn redundant_hierarchy_is_a_warning() {
+    let mut world = World::new();
+    let mut schedule = redundant_hierarchy();
+    schedule.initialize(&mut world).unwrap();
+    assert!(matches!(
+        schedule.warnings(),
+        [ScheduleBuildWarning::HierarchyRedundancy(_)]
+    ));
+}
+
+#[test]
+fn warm_up_reports_warnings_through_the_error_handler() {
+    let mut world = World::new();
+    world.insert_resource(DefaultErrorHandler(error::warn));
+    let mut schedule = redundant_hierarchy();
+    schedule.initialize_in_place(&mut world).unwrap();
+    assert!(schedule.warnings().is_empty());
+    // The built schedule is reused by the first run
+    schedule.run(&mut world);
+}
+
+// Formatting a `FeapError` with a backtrace is not ported yet, so the panic would abort
+#[cfg(not(feature = "backtrace"))]
+#[test]
+#[should_panic(expected = "Encountered an error in schedule")]
+fn warm_up_warnings_panic_by_default() {
+    let mut world = World::new();
+    redundant_hierarchy()
+        .initialize_in_place(&mut world)
+        .unwrap();
+}
+
+// Skipped with `backtrace` for the same reason as above
+#[cfg(not(feature = "backtrace"))]
+#[test]
+#[should_panic(expected = "Encountered an error in schedule")]
+fn warm_up_reports_warnings_left_by_an_earlier_build() {
+    let mut world = World::new();
+    let mut schedule = redundant_hierarchy();
+    schedule.initialize(&mut world).unwrap();
+    schedule.initialize_in_place(&mut world).unwrap();
+}
+
+#[test]
+fn before_and_after_order_functions() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems((b, a.before(b)));
+    assert_eq!(run_log(schedule), vec!["a", "b"]);
+
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems((a.after(b), b));
+    assert_eq!(run_log(schedule), vec!["b", "a"]);
+}
+
+#[test]
+fn before_orders_against_sets() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems((b.in_set(Child), a.before(Child)));
+    assert_eq!(run_log(schedule), vec!["a", "b"]);
+}
+
+#[test]
+fn ordering_against_duplicated_function_is_ambiguous() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems((b, b, a.before(b)));
+    assert!(matches!(
+        schedule.initialize(&mut world),
+        Err(ScheduleBuildError::SystemTypeSetAmbiguity(..))
+    ));
+}
+
+#[test]
+fn distinct_instances_are_left_out_of_type_set() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems(((b, b.distinct()).chain(), a.before(b)));
+    assert_eq!(run_log(schedule), vec!["a", "b", "b"]);
+}
+
+#[test]
+fn remove_systems_by_type_keeps_ordering_through_removed_systems() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems((a, system, b, system).chain());
+    assert_eq!(schedule.remove_systems_by_type(system), 2);
+    assert_eq!(schedule.remove_systems_by_type(system), 0);
+    // `a` and `b` would be unordered if the chain was not kept
+    assert_eq!(run_log(schedule), vec!["a", "b"]);
+}
+
+#[test]
+fn remove_systems_in_set_covers_nested_subsets() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.configure_sets(Child.in_set(Parent));
+    schedule.add_systems((a, b.in_set(Child), system.in_set(Parent), c).chain());
+    assert_eq!(schedule.remove_systems_in_set(Parent), 2);
+    assert_eq!(schedule.remove_systems_in_set(Child), 0);
+    // `a` and `c` would be unordered if the chain was not kept
+    assert_eq!(run_log(schedule), vec!["a", "c"]);
+}
+
+#[test]
+fn remove_systems_by_type_from_all_schedules() {
+    let mut schedules = Schedules::default();
+    schedules.add_systems(TestSchedule, (a, b).chain());
+    schedules.add_systems(OtherSchedule, a);
+    assert_eq!(schedules.remove_systems_by_type(a), 2);
+
+    let schedule = schedules.remove(TestSchedule).unwrap();
+    assert_eq!(run_log(schedule), vec!["b"]);
+    let schedule = schedules.remove(OtherSchedule).unwrap();
+    assert_eq!(run_log(schedule), Vec::<&str>::new());
+}
+
+#[test]
+fn node_ids_display_their_kind_and_key() {
+    let system = NodeId::System(SystemKey::from(KeyData::from_ffi((1 << 32) | 1)));
+    let set = NodeId::Set(SystemSetKey::from(KeyData::from_ffi((1 << 32) | 2)));
+    assert_eq!(system.to_string(), "system 1v1");
+    assert_eq!(set.to_string(), "system set 2v1");
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            CompactNodeIdAndDirection::from((set, Direction::Outgoing))
+        ),
+        format!("{:?}", (set, Direction::Outgoing))
+    );
+    assert_eq!(
+        format!("{:?}", CompactNodeIdPair::from((system, set))),
+        format!("{:?}", (system, set))
+    );
+}
+
+#[test]
+fn build_errors_render_node_names() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems((b, b, a.before(b)));
+    let error = schedule.initialize(&mut world).unwrap_err();
+    let message = error.to_string(schedule.graph(), &world);
+    assert!(message.starts_with("Tried to order against `SystemTypeSet("));
+    assert!(message.contains("call `.distinct()` on the duplicates"));
+
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems(a.in_set(Child).before(Child));
+    let error = schedule.initialize(&mut world).unwrap_err();
+    let message = error.to_string(schedule.graph(), &world);
+    assert!(message.starts_with("system `"));
+    assert!(message.contains(" (in set Child)` and system set `Child` have both `in_set`"));
+}
+
+#[test]
+fn build_warnings_render_node_names() {
+    let mut world = World::new();
+    let mut schedule = redundant_hierarchy();
+    schedule.initialize(&mut world).unwrap();
+    let message = schedule.warnings()[0].to_string(schedule.graph(), &world);
+    assert!(message.starts_with("The hierarchy of system sets contains redundant edges:\n -- "));
+    assert!(message.ends_with(
+        " (in sets Child, Parent)` cannot be child of system set `Parent`, longer path exists"
+    ));
+}
+
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Phase {
+    Input,
+    Simulation,
+    Presentation,
+}
+
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Numbered {
+    Late = 20,
+    Early = 10,
+    Last = 30,
+}
+
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+enum WithField {
+    Unit,
+    Field(u32),
+}
+
+#[derive(SystemSet, Debug)]
+enum Generic<T> {
+    Unit,
+    Marker(PhantomData<T>),
+}
+
+impl<T> Clone for Generic<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Unit => Self::Unit,
+            Self::Marker(marker) => Self::Marker(*marker),
+        }
+    }
+}
+
+impl<T> PartialEq for Generic<T> {
+    fn eq(&self, other: &Self) -> bool {
+        core::mem::discriminant(self) == core::mem::discriminant(other)
+    }
+}
+
+impl<T> Eq for Generic<T> {}
+
+impl<T> core::hash::Hash for Generic<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+    }
+}
+
+/// Reports whether `T` implements [`SystemSetVariants`], through autoref specialization
+struct VariantsProbe<T>(PhantomData<T>);
+
+trait Implemented {
+    fn has_variants(&self) -> bool {
+        true
+    }
+}
+
+impl<T: SystemSetVariants> Implemented for VariantsProbe<T> {}
+
+trait NotImplemented {
+    fn has_variants(&self) -> bool {
+        false
+    }
+}
+
+impl<T> NotImplemented for &VariantsProbe<T> {}
+
+/// Evaluates to `true` if `$ty` implements [`SystemSetVariants`]
+macro_rules! has_variants {
+    ($ty:ty) => {
+        (&VariantsProbe::<$ty>(PhantomData)).has_variants()
+    };
+}
+
+#[test]
+fn variants_are_listed_in_declaration_order() {
+    assert_eq!(
+        Phase::VARIANTS,
+        &[Phase::Input, Phase::Simulation, Phase::Presentation]
+    );
+    assert_eq!(Phase::variants().collect::<Vec<_>>(), Phase::VARIANTS);
+}
+
+#[test]
+fn explicit_discriminants_keep_declaration_order() {
+    assert_eq!(
+        Numbered::VARIANTS,
+        &[Numbered::Late, Numbered::Early, Numbered::Last]
+    );
+    assert_eq!(
+        Numbered::VARIANTS
+            .iter()
+            .map(|&set| set as u8)
+            .collect::<Vec<_>>(),
+        vec![20, 10, 30]
+    );
+}
+
+#[test]
+fn variants_are_only_derived_for_fieldless_enums_without_generics() {
+    assert!(has_variants!(Phase));
+    assert!(has_variants!(Numbered));
+    assert!(!has_variants!(WithField));
+    assert!(!has_variants!(Generic<u32>));
+    assert!(!has_variants!(Parent));
+}
+
+#[test]
+fn variant_hashes_are_stable() {
+    let hash = |set: &dyn SystemSet| {
+        let mut hasher = FixedHasher.build_hasher();
+        set.dyn_hash(&mut hasher);
+        core::hash::Hasher::finish(&hasher)
+    };
+    for (i, a) in Numbered::VARIANTS.iter().enumerate() {
+        // The hash only depends on the value, so it is the same for every copy and every run
+        assert_eq!(hash(a), hash(&a.clone()));
+        assert_eq!(a.intern(), a.clone().intern());
+        for b in &Numbered::VARIANTS[i + 1..] {
+            assert_ne!(hash(a), hash(b));
+            assert_ne!(a.intern(), b.intern());
+        }
+    }
+    assert_ne!(hash(&Phase::Input), hash(&Numbered::Late));
+    assert_eq!(hash(&WithField::Field(1)), hash(&WithField::Field(1)));
+    assert_ne!(hash(&WithField::Field(1)), hash(&WithField::Unit));
+}
+
+#[test]
+fn zero_sized_labels_intern_apart() {
+    let (test, other) = (TestSchedule.intern(), OtherSchedule.intern());
+    // Both labels are leaked at the same dangling address, only their type tells them apart
+    assert!(core::ptr::addr_eq(&*test, &*other));
+    assert_ne!(test, other);
+    assert_eq!(test, TestSchedule.intern());
+
+    let mut names = HashMap::<_, _>::default();
+    names.insert(test, "test");
+    names.insert(other, "other");
+    assert_eq!(names.len(), 2);
+    assert_eq!(names[&TestSchedule.intern()], "test");
+    assert_eq!(names[&OtherSchedule.intern()], "other");
+}
+
+fn chained_test() -> ScheduleTest {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems((a, system.in_set(Child), b).chain());
+    ScheduleTest::new(schedule)
+}
+
+#[test]
+fn schedule_test_checks_transitive_order() {
+    chained_test().assert_order(a, system).assert_order(a, b);
+}
+
+#[test]
+#[should_panic(expected = "to run before")]
+fn schedule_test_rejects_reversed_order() {
+    chained_test().assert_order(b, a);
+}
+
+#[test]
+#[should_panic(expected = "is not part of the schedule")]
+fn schedule_test_rejects_missing_systems() {
+    let mut test = ScheduleTest::empty(TestSchedule);
+    test.schedule_mut().add_systems(a);
+    test.assert_order(a, b);
+}
+
+#[test]
+#[should_panic(expected = "was added to the schedule more than once")]
+fn schedule_test_rejects_duplicated_systems() {
+    let mut test = chained_test();
+    test.schedule_mut().add_systems(a.after(b));
+    test.assert_order(system, a);
+}
+
+#[test]
+fn schedule_test_rebuilds_after_changes() {
+    let mut test = chained_test();
+    test.assert_order(a, system);
+    test.schedule_mut().remove_systems_by_type(system);
+    test.schedule_mut().add_systems(system.after(b));
+    test.assert_order(a, b).assert_order(b, system);
+}
+
+#[cfg(feature = "debug")]
+#[test]
+fn schedule_test_finds_systems_by_name() {
+    chained_test()
+        .assert_order_by_name("a", "b")
+        .assert_order_by_name("feap_ecs::schedule::tests::a", "system");
+}
+
+#[cfg(not(feature = "debug"))]
+#[test]
+#[should_panic(expected = "enable the `debug` feature")]
+fn schedule_test_names_need_the_debug_feature() {
+    chained_test().assert_order_by_name("a", "b");
+}
+
+crate::phase_sets! {
+    /// Phases used to test the `phase_sets!` macro
+    enum TestPhase in Parent {
+        First,
+        Second,
+        Third,
+    }
+}
+
+#[test]
+fn phase_sets_run_in_declaration_order() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.configure_sets(TestPhase::configs());
+    schedule.add_systems((
+        c.in_set(TestPhase::Third),
+        a.in_set(TestPhase::First),
+        b.in_set(TestPhase::Second),
+    ));
+    assert_eq!(run_log(schedule), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn phase_sets_are_added_to_their_parent() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.configure_sets(TestPhase::configs());
+    schedule.add_systems((
+        b.in_set(TestPhase::Second),
+        a.before(Parent),
+        c.after(Parent),
+    ));
+    assert_eq!(run_log(schedule), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn in_set_applies_to_every_system_of_a_tuple() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.add_systems(((a, b).chain().in_set(Child), c.before(Child)));
+    assert_eq!(run_log(schedule), vec!["c", "a", "b"]);
+}
+
+/// Runs the systems of a schedule in reverse order, ignoring their dependencies
+struct ReverseExecutor;
+
+impl SystemExecutor for ReverseExecutor {
+    fn kind(&self) -> ExecutorKind {
+        ExecutorKind::SingleThreaded
+    }
+
+    fn init(&mut self, schedule: &SystemSchedule) {
+        assert_eq!(schedule.systems().len(), schedule.system_dependents().len());
+    }
+
+    fn run(
+        &mut self,
+        schedule: &mut SystemSchedule,
+        world: &mut World,
+        _skip_systems: Option<&FixedBitSet>,
+        _error_handler: fn(FeapError, ErrorContext),
+        _hooks: Option<&mut dyn ExecutorHooks>,
+    ) {
+        for system in schedule.systems_mut().iter_mut().rev() {
+            system.system.run((), world).unwrap();
+        }
+    }
+}
+
+#[test]
+fn custom_executors_run_the_schedule() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.set_executor(ReverseExecutor);
+    schedule.add_systems((a, b, c).chain());
+    assert_eq!(run_log(schedule), vec!["c", "b", "a"]);
+}
+
+#[test]
+fn executor_kind_keeps_a_custom_executor_of_the_same_kind() {
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.set_executor(ReverseExecutor);
+    schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    schedule.add_systems((a, b).chain());
+    assert_eq!(run_log(schedule), vec!["b", "a"]);
+}
+
+/// Events recorded by [`RecordingHooks`] and by the systems they observe, in the order they happen
+#[derive(Resource, Clone, Default)]
+struct Events(Arc<RwLock<Vec<&'static str>>>);
+
+impl Events {
+    fn push(&self, event: &'static str) {
+        self.0.write().unwrap().push(event);
+    }
+
+    fn take(&self) -> Vec<&'static str> {
+        core::mem::take(&mut *self.0.write().unwrap())
+    }
+}
+
+struct RecordingHooks(Events);
+
+impl ExecutorHooks for RecordingHooks {
+    fn on_schedule_start(&mut self, _label: InternedScheduleLabel) {
+        self.0.push("schedule");
+    }
+
+    fn on_system_start(&mut self, _system: &ScheduleSystem) {
+        self.0.push("start");
+    }
+
+    fn on_system_end(&mut self, _system: &ScheduleSystem) {
+        self.0.push("end");
+    }
+}
+
+fn record_a(world: &mut World) {
+    world.get_resource::<Events>().unwrap().push("a");
+}
+
+fn record_b(world: &mut World) {
+    world.get_resource::<Events>().unwrap().push("b");
+}
+
+/// Builds a world and a schedule running `systems` with [`RecordingHooks`]
+fn recorded<M>(systems: impl IntoScheduleConfigs<ScheduleSystem, M>) -> (World, Schedule, Events) {
+    let events = Events::default();
+    let mut world = World::new();
+    world.insert_resource(events.clone());
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.set_executor_hooks(RecordingHooks(events.clone()));
+    schedule.add_systems(systems);
+    (world, schedule, events)
+}
+
+#[test]
+fn executor_hooks_are_called_around_each_system() {
+    let (mut world, mut schedule, events) = recorded((record_a, record_b).chain());
+    schedule.run(&mut world);
+    assert_eq!(
+        events.take(),
+        vec!["schedule", "start", "a", "end", "start", "b", "end"]
+    );
+
+    assert!(schedule.take_executor_hooks().is_some());
+    schedule.run(&mut world);
+    assert_eq!(events.take(), vec!["a", "b"]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn executor_hooks_see_the_end_of_panicking_systems() {
+    fn panics(_world: &mut World) {
+        panic!("system failed");
+    }
+
+    let (mut world, mut schedule, events) = recorded((record_a, panics, record_b).chain());
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        schedule.run(&mut world);
+    }));
+    assert!(result.is_err());
+    assert_eq!(
+        events.take(),
+        vec!["schedule", "start", "a", "end", "start", "end"]
+    );
+}
+
+/// A run condition that always passes, used where only the built schedule is inspected
+struct Always;
+
+impl System for Always {
+    type In = ();
+    type Out = bool;
+
+    fn name(&self) -> DebugName {
+        DebugName::type_name::<Self>()
+    }
+
+    fn initialize(&mut self, _world: &mut World) -> FilteredAccessSet {
+        FilteredAccessSet::default()
+    }
+
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> {
+        Vec::new()
+    }
+
+    unsafe fn run_unsafe(
+        &mut self,
+        _input: (),
+        _world: UnsafeWorldCell,
+    ) -> Result<bool, RunSystemError> {
+        Ok(true)
+    }
+
+    fn apply_deferred(&mut self, _world: &mut World) {}
+
+    unsafe fn validate_param_unsafe(
+        &mut self,
+        _world: UnsafeWorldCell,
+    ) -> Result<(), SystemParamValidationError> {
+        Ok(())
+    }
+
+    fn check_change_tick(&mut self, _check: CheckChangeTicks) {}
+}
+
+// SAFETY: `Always` does not access the world
+unsafe impl ReadOnlySystem for Always {}
+
+/// Adds [`Always`] to a single system
+///
+/// Set and collective run conditions are not ported yet, so only single systems can have one
+fn run_always<M>(
+    system: impl IntoScheduleConfigs<ScheduleSystem, M>,
+) -> ScheduleConfigs<ScheduleSystem> {
+    let mut configs = system.into_configs();
+    if let ScheduleConfigs::ScheduleConfig(config) = &mut configs {
+        config.conditions.push(Box::new(Always));
+    }
+    configs
+}
+
+fn name<M>(system: impl IntoSystem<(), (), M>) -> String {
+    IntoSystem::into_system(system).name().to_string()
+}
+
+#[test]
+fn debug_report_shows_sets_conditions_and_edges() {
+    // The type sets of `a`, `b` and `c` are hidden, so the systems hang off their own sets
+    let mut world = World::new();
+    let mut schedule = Schedule::new(TestSchedule);
+    schedule.configure_sets(Child.in_set(Parent));
+    schedule.add_systems((run_always(a.in_set(Child)), b.in_set(Parent)).chain());
+    schedule.add_systems(c.after(Parent));
+
+    let (a, b, c) = (name(a), name(b), name(c));
+    assert_eq!(
+        schedule.debug_report(&mut world).unwrap(),
+        format!(
+            "TestSchedule\n\
+             \x20 - {c} (after: Parent)\n\
+             \x20 + Parent\n\
+             \x20   - {b} (after: {a})\n\
+             \x20   + Child\n\
+             \x20     - {a} [1 condition(s)]\n"
+        )
+    );
+}
@@ -15,3 +15,12 @@ pub struct Storages {
     /// Backing storage for resources
     pub resources: Resources<true>,
 }
+
+impl Storages {
+    /// Shrinks the capacity of every storage as much as possible
+    ///
+    /// This is useful to return memory after despawning a large number of entities
+    pub fn shrink_to_fit(&mut self) {
+        self.resources.shrink_to_fit();
+    }
+}
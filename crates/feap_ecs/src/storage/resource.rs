@@ -2,6 +2,7 @@ use crate::{
     change_detection::{MaybeLocation, MutUntyped, TicksMut},
     component::{CheckChangeTicks, ComponentId, ComponentTicks, Components, Tick, TickCells},
     storage::{blob_array::BlobArray, sparse_set::SparseSet},
+    world::AccessGuard,
 };
 use core::{cell::UnsafeCell, panic::Location};
 use feap_core::ptr::{OwningPtr, Ptr, UnsafeCellDeref};
@@ -62,7 +63,8 @@ impl<const SEND: bool> ResourceData<SEND> {
         caller: MaybeLocation,
     ) {
         if self.is_present() {
-            todo!()
+            self.validate_access();
+            unsafe { self.data.replace_unchecked(Self::ROW, value) };
         } else {
             #[cfg(feature = "std")]
             if !SEND {
@@ -117,6 +119,7 @@ impl<const SEND: bool> ResourceData<SEND> {
             value: unsafe { ptr.assert_unique() },
             ticks: unsafe { TicksMut::from_tick_cells(ticks, last_run, this_run) },
             changed_by: unsafe { caller.map(|caller| caller.deref_mut()) },
+            access: AccessGuard::default(),
         })
     }
 
@@ -223,6 +226,20 @@ impl<const SEND: bool> Resources<SEND> {
         })
     }
 
+    /// An iterator visiting all resources in arbitrary order
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, &ResourceData<SEND>)> {
+        self.resources.iter().map(|(id, data)| (*id, data))
+    }
+
+    /// A mutable iterator visiting all resources in arbitrary order
+    #[inline]
+    pub(crate) fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (ComponentId, &mut ResourceData<SEND>)> {
+        self.resources.iter_mut().map(|(id, data)| (*id, data))
+    }
+
     /// Gets read-only access to a resource, if it exists
     #[inline]
     pub fn get(&self, component_id: ComponentId) -> Option<&ResourceData<SEND>> {
@@ -235,6 +252,17 @@ impl<const SEND: bool> Resources<SEND> {
         self.resources.get_mut(component_id)
     }
 
+    /// Returns the number of resources that can be stored without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.resources.capacity()
+    }
+
+    /// Shrinks the capacity of the storage as much as possible
+    pub fn shrink_to_fit(&mut self) {
+        self.resources.shrink_to_fit();
+    }
+
     pub(crate) fn check_change_ticks(&mut self, check: CheckChangeTicks) {
         for info in self.resources.values_mut() {
             info.check_change_ticks(check);
@@ -50,6 +50,11 @@ impl<I: SparseSetIndex, V> SparseArray<I, V> {
         }
         self.values[index] = Some(value);
     }
+
+    /// Shrinks the capacity of the array as much as possible
+    pub fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+    }
 }
 
 /// A data structure that blends dense and sparse storage
@@ -95,6 +100,11 @@ macro_rules! impl_sparse_set {
                     .map(move |dense_index| unsafe { dense.get_unchecked_mut(dense_index.get()) })
             }
 
+            /// Returns an iterator visiting all key-value pairs in arbitrary order
+            pub fn iter(&self) -> impl Iterator<Item = (&I, &V)> {
+                self.indices.iter().zip(self.dense.iter())
+            }
+
             /// Returns an iterator visiting all values mutably in arbitrary order
             pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
                 self.dense.iter_mut()
@@ -126,6 +136,19 @@ impl<I: SparseSetIndex, V> SparseSet<I, V> {
             unsafe { self.dense.get_unchecked_mut(dense_index) }
         }
     }
+
+    /// Returns the number of values the set can hold without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.dense.capacity()
+    }
+
+    /// Shrinks the capacity of the set as much as possible
+    pub fn shrink_to_fit(&mut self) {
+        self.dense.shrink_to_fit();
+        self.indices.shrink_to_fit();
+        self.sparse.shrink_to_fit();
+    }
 }
 
 /// Represents something that can be stored in a [`SparseSet`] as an integer
@@ -3,7 +3,7 @@ use crate::system::system_param::SystemParamValidationError;
 use crate::system::RunSystemError;
 use crate::world::UnsafeWorldCell;
 use crate::{
-    component::Tick,
+    component::{CheckChangeTicks, Tick},
     query::FilteredAccessSet,
     schedule::{InternedSystemSet, SystemSet, SystemTypeSet},
     system::{
@@ -119,6 +119,11 @@ where
         // All exclusive system params are always available
         Ok(())
     }
+
+    #[inline]
+    fn check_change_tick(&mut self, check: CheckChangeTicks) {
+        self.system_meta.last_run.check_tick(check);
+    }
 }
 
 /// A trait implemented for all exclusive system functions that can be used as [`System`]s
@@ -159,6 +164,10 @@ macro_rules! impl_exclusive_system_function {
             non_snake_case,
             reason = "Certain variable names are provided by the caller, not by us."
         )]
+        #[allow(
+            clippy::too_many_arguments,
+            reason = "`call_inner` takes one argument per system parameter."
+        )]
         impl<Out, Func, $($param: ExclusiveSystemParam),*> ExclusiveSystemParamFunction<fn($($param,)*) -> Out> for Func
         where
             Func: Send + Sync + 'static,
@@ -192,6 +201,10 @@ macro_rules! impl_exclusive_system_function {
             non_snake_case,
             reason = "Certain variable names are provided by the caller, not by us."
         )]
+        #[allow(
+            clippy::too_many_arguments,
+            reason = "`call_inner` takes one argument per system parameter."
+        )]
         impl<In, Out, Func, $($param: ExclusiveSystemParam),*> ExclusiveSystemParamFunction<(HasExclusiveSystemInput, fn(In, $($param,)*) -> Out)> for Func
         where
             Func: Send + Sync + 'static,
@@ -207,7 +220,17 @@ macro_rules! impl_exclusive_system_function {
 
             #[inline]
             fn run(&mut self, world: &mut World, input: In::Inner<'_>, param_value: ExclusiveSystemParamItem< ($($param,)*)>) -> Out {
-                todo!()
+                fn call_inner<In: SystemInput, Out, $($param,)*>(
+                    _: PhantomData<In>,
+                    mut f: impl FnMut(In::Param<'_>, &mut World, $($param,)*) -> Out,
+                    input: In::Inner<'_>,
+                    world: &mut World,
+                    $($param: $param,)*
+                ) -> Out {
+                    f(In::wrap(input), world, $($param,)*)
+                }
+                let ($($param,)*) = param_value;
+                call_inner(PhantomData::<In>, self, input, world, $($param),*)
             }
         }
     };
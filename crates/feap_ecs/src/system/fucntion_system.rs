@@ -2,7 +2,7 @@ use super::{
     IntoSystem, RunSystemError, System, SystemInput, SystemParam, SystemParamItem, SystemStateFlags,
 };
 use crate::{
-    component::Tick,
+    component::{CheckChangeTicks, Tick},
     error::FeapError,
     query::FilteredAccessSet,
     schedule::{InternedSystemSet, SystemSet, SystemTypeSet},
@@ -77,7 +77,7 @@ where
 
     #[inline]
     fn name(&self) -> DebugName {
-        todo!()
+        self.system_meta.name.clone()
     }
 
     #[inline]
@@ -127,6 +127,11 @@ where
     ) -> Result<(), SystemParamValidationError> {
         todo!()
     }
+
+    #[inline]
+    fn check_change_tick(&mut self, check: CheckChangeTicks) {
+        self.system_meta.last_run.check_tick(check);
+    }
 }
 
 /// A marker type used to distinguish regular function systems from exclusive function systems
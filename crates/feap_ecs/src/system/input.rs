@@ -2,12 +2,18 @@ use super::System;
 use core::ops::{Deref, DerefMut};
 use variadics_please::all_tuples;
 
+#[cfg(test)]
+mod tests;
+
 /// Trait for types that can be used as input to [`System`]s
 pub trait SystemInput: Sized {
     /// The wrapper input type that is defined as the first argument to [`FunctionSystem`]s
     type Param<'i>: SystemInput;
     /// The inner input type that is passed to functions that run systems
     type Inner<'i>;
+
+    /// Converts a [`SystemInput::Inner`] into a [`SystemInput::Param`]
+    fn wrap(this: Self::Inner<'_>) -> Self::Param<'_>;
 }
 
 /// Shorthand way to get the [`System::In`] for a [`System`] as a [`SystemInput::Inner`]
@@ -26,6 +32,10 @@ pub struct In<T>(pub T);
 impl<T: 'static> SystemInput for In<T> {
     type Param<'i> = In<T>;
     type Inner<'i> = T;
+
+    fn wrap(this: Self::Inner<'_>) -> Self::Param<'_> {
+        In(this)
+    }
 }
 
 impl<T> Deref for In<T> {
@@ -42,6 +52,60 @@ impl<T> DerefMut for In<T> {
     }
 }
 
+/// A [`SystemInput`] type which denotes that a [`System`] receives
+/// a read-only reference to a value of type `T` from its caller
+///
+/// This is similar to [`In`] but takes a reference to a value instead of the value itself
+#[derive(Debug)]
+pub struct InRef<'i, T: ?Sized>(pub &'i T);
+
+impl<T: ?Sized + 'static> SystemInput for InRef<'_, T> {
+    type Param<'i> = InRef<'i, T>;
+    type Inner<'i> = &'i T;
+
+    fn wrap(this: Self::Inner<'_>) -> Self::Param<'_> {
+        InRef(this)
+    }
+}
+
+impl<'i, T: ?Sized> Deref for InRef<'i, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+/// A [`SystemInput`] type which denotes that a [`System`] receives
+/// a mutable reference to a value of type `T` from its caller
+///
+/// This is similar to [`In`] but takes a mutable reference to a value instead of the value itself
+#[derive(Debug)]
+pub struct InMut<'a, T: ?Sized>(pub &'a mut T);
+
+impl<T: ?Sized + 'static> SystemInput for InMut<'_, T> {
+    type Param<'i> = InMut<'i, T>;
+    type Inner<'i> = &'i mut T;
+
+    fn wrap(this: Self::Inner<'_>) -> Self::Param<'_> {
+        InMut(this)
+    }
+}
+
+impl<'i, T: ?Sized> Deref for InMut<'i, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'i, T: ?Sized> DerefMut for InMut<'i, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
 macro_rules! impl_system_input_tuple {
     ($(#[$meta:meta])* $($name:ident),*) => {
         $(#[$meta])*
@@ -49,18 +113,22 @@ macro_rules! impl_system_input_tuple {
             type Param<'i> = ($($name::Param<'i>,)*);
             type Inner<'i> = ($($name::Inner<'i>,)*);
 
-            // #[expect(
-            //     clippy::allow_attributes,
-            //     reason = "This is in a macro; as such, the below lints may not always apply."
-            // )]
-            // #[allow(
-            //     non_snake_case,
-            //     reason = "Certain variable names are provided by the caller, not by us."
-            // )]
-            // #[allow(
-            //     clippy::unused_unit,
-            //     reason = "Zero-length tuples won't have anything to wrap."
-            // )]
+            #[expect(
+                clippy::allow_attributes,
+                reason = "This is in a macro; as such, the below lints may not always apply."
+            )]
+            #[allow(
+                non_snake_case,
+                reason = "Certain variable names are provided by the caller, not by us."
+            )]
+            #[allow(
+                clippy::unused_unit,
+                reason = "Zero-length tuples won't have anything to wrap."
+            )]
+            fn wrap(this: Self::Inner<'_>) -> Self::Param<'_> {
+                let ($($name,)*) = this;
+                ($($name::wrap($name),)*)
+            }
         }
     }
 }
@@ -0,0 +1,57 @@
+//! Tests for [`InRef`] and [`InMut`] inputs, run through [`World::run_system_once_with`]
+
+use super::{In, InMut, InRef};
+use crate::world::World;
+use alloc::vec::Vec;
+
+fn sum(InRef(values): InRef<[u32]>, _: &mut World) -> u32 {
+    values.iter().sum()
+}
+
+fn double(InMut(value): InMut<u32>, _: &mut World) {
+    *value *= 2;
+}
+
+fn push(InMut(values): InMut<Vec<u32>>, _: &mut World) -> usize {
+    values.push(values.len() as u32);
+    values.len()
+}
+
+fn copy((InRef(from), InMut(to)): (InRef<u32>, InMut<u32>), _: &mut World) {
+    *to = *from;
+}
+
+fn add(In(value): In<u32>, _: &mut World) -> u32 {
+    value + 1
+}
+
+#[test]
+fn in_ref_inputs_are_read() {
+    let mut world = World::new();
+    let values = [1, 2, 3];
+    assert_eq!(world.run_system_once_with(sum, &values[..]).unwrap(), 6);
+    assert_eq!(world.run_system_once_with(sum, &[][..]).unwrap(), 0);
+}
+
+#[test]
+fn in_mut_inputs_are_mutated() {
+    let mut world = World::new();
+    let mut value = 3;
+    world.run_system_once_with(double, &mut value).unwrap();
+    world.run_system_once_with(double, &mut value).unwrap();
+    assert_eq!(value, 12);
+
+    let mut values = Vec::new();
+    assert_eq!(world.run_system_once_with(push, &mut values).unwrap(), 1);
+    assert_eq!(world.run_system_once_with(push, &mut values).unwrap(), 2);
+    assert_eq!(values, [0, 1]);
+}
+
+#[test]
+fn inputs_can_be_combined_in_tuples() {
+    let mut world = World::new();
+    let mut to = 0;
+    world.run_system_once_with(copy, (&7, &mut to)).unwrap();
+    assert_eq!(to, 7);
+    assert_eq!(world.run_system_once_with(add, to).unwrap(), 8);
+}
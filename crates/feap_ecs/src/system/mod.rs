@@ -8,10 +8,11 @@ mod system_param;
 mod error;
 
 pub use error::RunSystemError;
-pub use input::SystemInput;
+pub use fucntion_system::SystemMeta;
+pub use input::{In, InMut, InRef, SystemIn, SystemInput};
 pub use schedule_system::ScheduleSystem;
 pub use system::{SystemStateFlags, BoxedSystem, ReadOnlySystem, System};
-pub use system_param::{Local, SystemParam, SystemParamItem};
+pub use system_param::{Local, SystemParam, SystemParamItem, SystemParamValidationError};
 
 /// Conversion trait to turn something into a [`System`]
 /// Use this to get a system from a function. Also note that every system implements this as well
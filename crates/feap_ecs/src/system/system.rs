@@ -1,5 +1,6 @@
 use super::input::{SystemIn, SystemInput};
 use crate::{
+    component::CheckChangeTicks,
     query::FilteredAccessSet,
     schedule::InternedSystemSet,
     system::{system_param::SystemParamValidationError, RunSystemError},
@@ -74,6 +75,17 @@ pub trait System: Send + Sync + 'static {
         unsafe { self.run_unsafe(input, world_cell) }
     }
 
+    /// Runs the system with the given input in the world, then applies any deferred parameters
+    fn run(
+        &mut self,
+        input: SystemIn<'_, Self>,
+        world: &mut World,
+    ) -> Result<Self::Out, RunSystemError> {
+        let ret = self.run_without_applying_deferred(input, world)?;
+        self.apply_deferred(world);
+        Ok(ret)
+    }
+
     /// Applies any [`Deferred`] system parameters
     /// This is where [`Commands`] are applied
     fn apply_deferred(&mut self, world: &mut World);
@@ -84,6 +96,14 @@ pub trait System: Send + Sync + 'static {
         &mut self,
         world: UnsafeWorldCell,
     ) -> Result<(), SystemParamValidationError>;
+
+    /// Clamps the [`Tick`]s stored on this system if they are older than [`MAX_CHANGE_AGE`]
+    /// This is called by [`World::check_change_ticks`] for every system stored in [`Schedules`]
+    ///
+    /// [`Tick`]: crate::component::Tick
+    /// [`MAX_CHANGE_AGE`]: crate::component::MAX_CHANGE_AGE
+    /// [`Schedules`]: crate::schedule::Schedules
+    fn check_change_tick(&mut self, check: CheckChangeTicks);
 }
 
 /// A convenience type alias for a boxed [`System`] trait object
@@ -0,0 +1,102 @@
+#[cfg(debug_assertions)]
+use crate::component::{ComponentId, Components};
+#[cfg(debug_assertions)]
+use alloc::{collections::BTreeSet, string::String, sync::Arc};
+#[cfg(debug_assertions)]
+use feap_core::sync::{PoisonError, RwLock};
+
+/// Records the exclusive borrows of components and resources that are alive through the mutable
+/// [`UnsafeWorldCell`](super::UnsafeWorldCell)s of a [`World`](super::World), so that aliasing
+/// violations panic in debug builds instead of being silent undefined behavior
+///
+/// Each exclusive borrow is released by the [`AccessGuard`] stored in the returned
+/// [`Mut`](crate::change_detection::Mut), so sequential borrows never conflict. The guard keeps
+/// the log alive through an [`Arc`], so it holds no borrow of the world.
+/// Shared borrows are plain references which cannot be tracked: they are checked against live
+/// exclusive borrows but not recorded, so an exclusive borrow taken while a shared one is alive
+/// is not detected
+#[cfg(debug_assertions)]
+#[derive(Debug, Default)]
+pub(crate) struct AccessLog {
+    exclusive: RwLock<BTreeSet<ComponentId>>,
+}
+
+#[cfg(debug_assertions)]
+impl AccessLog {
+    /// Forgets all recorded borrows
+    pub(crate) fn clear(&self) {
+        self.exclusive
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Checks a borrow of `component_id` against the live exclusive borrows, panicking on conflict
+    ///
+    /// Exclusive borrows are recorded until the returned guard is dropped
+    #[track_caller]
+    pub(crate) fn record(
+        self: &Arc<Self>,
+        component_id: ComponentId,
+        exclusive: bool,
+        components: &Components,
+    ) -> AccessGuard {
+        let mut borrows = self
+            .exclusive
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if borrows.contains(&component_id) {
+            drop(borrows);
+            panic!(
+                "{} borrow of `{}` conflicts with a live exclusive borrow through the same world",
+                if exclusive { "exclusive" } else { "shared" },
+                component_name(component_id, components)
+            );
+        }
+        if !exclusive {
+            return AccessGuard::default();
+        }
+        borrows.insert(component_id);
+        AccessGuard {
+            borrow: Some((self.clone(), component_id)),
+        }
+    }
+
+    /// Forgets the exclusive borrow of `component_id`, if any
+    fn release(&self, component_id: ComponentId) {
+        self.exclusive
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&component_id);
+    }
+}
+
+/// Releases an exclusive borrow recorded in an [`AccessLog`] when dropped
+///
+/// This does nothing for shared borrows, borrows that are not tracked and in release builds
+#[derive(Default)]
+pub(crate) struct AccessGuard {
+    #[cfg(debug_assertions)]
+    borrow: Option<(Arc<AccessLog>, ComponentId)>,
+}
+
+#[cfg(debug_assertions)]
+impl Drop for AccessGuard {
+    fn drop(&mut self) {
+        if let Some((log, component_id)) = self.borrow.take() {
+            log.release(component_id);
+        }
+    }
+}
+
+/// Returns the name of the component, or its id if names are not kept
+#[cfg(debug_assertions)]
+fn component_name(component_id: ComponentId, components: &Components) -> String {
+    #[cfg(feature = "debug")]
+    if let Some(name) = components.get_name(component_id) {
+        return alloc::format!("{name}");
+    }
+    #[cfg(not(feature = "debug"))]
+    let _ = components;
+    alloc::format!("{component_id:?}")
+}
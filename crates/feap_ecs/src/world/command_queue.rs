@@ -24,4 +24,11 @@ impl RawCommandQueue {
     pub unsafe fn is_empty(&self) -> bool {
         (unsafe { *self.cursor.as_ref() }) >= (unsafe { self.bytes.as_ref() }).len()
     }
+
+    /// Returns the number of bytes of commands that are queued but not applied yet
+    pub unsafe fn len(&self) -> usize {
+        (unsafe { self.bytes.as_ref() })
+            .len()
+            .saturating_sub(unsafe { *self.cursor.as_ref() })
+    }
 }
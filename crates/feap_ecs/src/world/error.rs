@@ -4,3 +4,13 @@ use crate::schedule::InternedScheduleLabel;
 #[derive(thiserror::Error, Debug)]
 #[error("The schedule with the label {0:?} was not found")]
 pub struct TryRunScheduleError(pub InternedScheduleLabel);
+
+/// The error type returned by [`World::try_new`] if the supply of unique [`WorldId`]s has been exhausted
+///
+/// [`WorldId`]: super::WorldId
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("More worlds have been created than supported ({live_worlds} worlds are still alive)")]
+pub struct WorldIdExhaustedError {
+    /// The number of worlds alive when the world creation failed
+    pub live_worlds: usize,
+}
@@ -9,6 +9,10 @@ use feap_core::sync::atomic::{AtomicUsize, Ordering};
 pub struct WorldId(usize);
 
 static MAX_WORLD_ID: AtomicUsize = AtomicUsize::new(0);
+/// Number of worlds that have been created and not dropped yet
+static LIVE_WORLDS: AtomicUsize = AtomicUsize::new(0);
+/// Number of live worlds above which a warning is logged, `usize::MAX` to never warn
+static LIVE_WORLDS_SOFT_LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
 
 impl WorldId {
     /// Create a new, unique [`WorldId`]. 
@@ -21,6 +25,43 @@ impl WorldId {
             .map(WorldId)
             .ok()
     }
+
+    /// Returns the number of [`WorldId`]s handed out so far, including those of dropped worlds
+    pub fn allocated() -> usize {
+        MAX_WORLD_ID.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of worlds that are currently alive
+    pub fn live_worlds() -> usize {
+        LIVE_WORLDS.load(Ordering::Relaxed)
+    }
+
+    /// Sets the number of live worlds above which a warning is logged, or `None` to never warn
+    ///
+    /// The warning is logged whenever a world is created while the limit is exceeded, which helps
+    /// long-running processes notice worlds that are leaked instead of dropped
+    pub fn set_soft_limit(limit: Option<usize>) {
+        LIVE_WORLDS_SOFT_LIMIT.store(limit.unwrap_or(usize::MAX), Ordering::Relaxed);
+    }
+
+    /// Returns `true` if more worlds are alive than the soft limit allows
+    pub fn exceeds_soft_limit() -> bool {
+        Self::live_worlds() > LIVE_WORLDS_SOFT_LIMIT.load(Ordering::Relaxed)
+    }
+
+    /// Records that a world using this id was created
+    pub(super) fn track_world(self) {
+        let live = LIVE_WORLDS.fetch_add(1, Ordering::Relaxed) + 1;
+        let limit = LIVE_WORLDS_SOFT_LIMIT.load(Ordering::Relaxed);
+        if live > limit {
+            log::warn!("{live} worlds are alive, more than the soft limit of {limit}");
+        }
+    }
+
+    /// Records that the world using this id was dropped
+    pub(super) fn untrack_world(self) {
+        LIVE_WORLDS.fetch_sub(1, Ordering::Relaxed);
+    }
 }
 
 impl FromWorld for WorldId {
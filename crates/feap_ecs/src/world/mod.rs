@@ -1,16 +1,27 @@
+mod access_log;
 mod command_queue;
 mod deferred_world;
 mod error;
 mod identifier;
+mod stats;
+mod view;
+
+#[cfg(test)]
+mod tests;
 
 pub use deferred_world::DeferredWorld;
+pub use error::WorldIdExhaustedError;
 pub use identifier::WorldId;
+pub use stats::WorldStats;
+pub use view::WorldView;
+
+pub(crate) use access_log::AccessGuard;
 
 use self::error::*;
 use crate::{
     change_detection::{MaybeLocation, Mut, MutUntyped, TicksMut},
     component::{
-        CheckChangeTicks, Component, ComponentId, ComponentIds, ComponentTicks,
+        CheckChangeTicks, Component, ComponentId, ComponentIds, ComponentInfo, ComponentTicks,
         Components, ComponentsRegistrator, Tick, CHECK_TICK_THRESHOLD,
     },
     entity::Entities,
@@ -19,18 +30,24 @@ use crate::{
     lifecycle::RemovedComponentMessages,
     query::DebugCheckedUnwrap,
     resource::Resource,
-    schedule::{Schedule, ScheduleLabel, Schedules},
+    schedule::{Schedule, ScheduleBuildError, ScheduleLabel, Schedules},
     storage::{ResourceData, Storages},
+    system::{IntoSystem, RunSystemError, System, SystemInput},
     world::command_queue::RawCommandQueue,
 };
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error, Layout},
+    vec::Vec,
+};
 use core::{
     any::TypeId,
     cell::UnsafeCell,
     marker::PhantomData,
-    ptr,
+    num::NonZeroUsize,
+    ptr::{self, NonNull},
     sync::atomic::{AtomicU32, Ordering},
 };
-use feap_core::ptr::{OwningPtr, Ptr, UnsafeCellDeref};
+use feap_core::ptr::{dangling_with_align, OwningPtr, Ptr, PtrMut, UnsafeCellDeref};
 use feap_utils::debug_info::DebugName;
 
 /// Variant of the [`World`] where resource and component accesses take `&self`, and the responsibility to avoid
@@ -74,6 +91,10 @@ impl<'w> UnsafeWorldCell<'w> {
     /// Creates [`UnsafeWorldCell`] that can be used to access everything mutably
     #[inline]
     pub(crate) fn new_mutable(world: &'w mut World) -> Self {
+        // Borrows made through earlier cells have ended, since `world` is borrowed mutably,
+        // even if their guards are not dropped yet
+        #[cfg(debug_assertions)]
+        world.access_log.clear();
         Self {
             ptr: ptr::from_mut(world),
             #[cfg(debug_assertions)]
@@ -153,7 +174,13 @@ impl<'w> UnsafeWorldCell<'w> {
     /// dereferenced after the borrow of the [`World`] ends
     #[inline]
     pub unsafe fn get_resource_by_id(self, component_id: ComponentId) -> Option<Ptr<'w>> {
-        todo!()
+        let (ptr, _, _) = unsafe { self.storages() }
+            .resources
+            .get(component_id)?
+            .get_with_ticks()?;
+        // Shared borrows are not recorded, so the guard does nothing
+        let _ = self.record_access(component_id, false);
+        Some(ptr)
     }
 
     /// Gets a pointer to the resource with the id [`ComponentId`] if it exists
@@ -169,6 +196,7 @@ impl<'w> UnsafeWorldCell<'w> {
             .resources
             .get(component_id)?
             .get_with_ticks()?;
+        let access = self.record_access(component_id, true);
 
         let ticks = unsafe {
             TicksMut::from_tick_cells(ticks, self.last_change_tick(), self.change_tick())
@@ -178,9 +206,28 @@ impl<'w> UnsafeWorldCell<'w> {
             value: unsafe { ptr.assert_unique() },
             ticks,
             changed_by: unsafe { caller.map(|caller| caller.deref_mut()) },
+            access,
         })
     }
 
+    /// Checks a borrow of `component_id` in debug builds, panicking if it conflicts with a live
+    /// exclusive borrow
+    ///
+    /// Exclusive borrows stay recorded until the returned guard is dropped.
+    /// Read-only cells are not checked, as they can only hand out shared borrows
+    #[inline]
+    #[track_caller]
+    fn record_access(self, _component_id: ComponentId, _exclusive: bool) -> AccessGuard {
+        #[cfg(debug_assertions)]
+        if self.allows_mutable_access {
+            let world = unsafe { self.unsafe_world() };
+            return world
+                .access_log
+                .record(_component_id, _exclusive, &world.components);
+        }
+        AccessGuard::default()
+    }
+
     /// Gets the current change tick of this world
     #[inline]
     pub fn change_tick(self) -> Tick {
@@ -210,24 +257,19 @@ pub struct World {
     pub(crate) last_change_tick: Tick,
     pub(crate) last_check_tick: Tick,
     pub(crate) command_queue: RawCommandQueue,
+    #[cfg(debug_assertions)]
+    pub(crate) access_log: alloc::sync::Arc<access_log::AccessLog>,
 }
 
 impl Default for World {
     fn default() -> Self {
-        let mut world = Self {
-            id: WorldId::new().expect("More worlds have been created than supported"),
-            entities: Entities::new(),
-            components: Components::default(),
-            component_ids: ComponentIds::default(),
-            storages: Storages::default(),
-            removed_components: RemovedComponentMessages::default(),
-            change_tick: AtomicU32::new(1),
-            last_change_tick: Tick::new(0),
-            last_check_tick: Tick::new(0),
-            command_queue: RawCommandQueue::new(),
-        };
-        world.bootstrap();
-        world
+        Self::try_new().unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl Drop for World {
+    fn drop(&mut self) {
+        self.id.untrack_world();
     }
 }
 
@@ -242,6 +284,34 @@ impl World {
         World::default()
     }
 
+    /// Creates a new empty [`World`], or returns an error if the supply of unique
+    /// [`WorldId`]s has been exhausted
+    ///
+    /// Unlike [`World::new`], this does not panic, which lets long-running processes that
+    /// create and drop many worlds handle the failure
+    pub fn try_new() -> Result<World, WorldIdExhaustedError> {
+        let id = WorldId::new().ok_or_else(|| WorldIdExhaustedError {
+            live_worlds: WorldId::live_worlds(),
+        })?;
+        id.track_world();
+        let mut world = Self {
+            id,
+            entities: Entities::new(),
+            components: Components::default(),
+            component_ids: ComponentIds::default(),
+            storages: Storages::default(),
+            removed_components: RemovedComponentMessages::default(),
+            change_tick: AtomicU32::new(1),
+            last_change_tick: Tick::new(0),
+            last_check_tick: Tick::new(0),
+            command_queue: RawCommandQueue::new(),
+            #[cfg(debug_assertions)]
+            access_log: Default::default(),
+        };
+        world.bootstrap();
+        Ok(world)
+    }
+
     /// Retrieves this [`World`]s unique ID
     #[inline]
     pub fn id(&self) -> WorldId {
@@ -260,6 +330,32 @@ impl World {
         UnsafeWorldCell::new_readonly(self)
     }
 
+    /// Creates a new [`WorldView`], a read-only view of the world that can be shared across threads
+    #[inline]
+    pub fn as_view(&self) -> WorldView<'_> {
+        WorldView::new(self)
+    }
+
+    /// Retrieves this world's [`Entities`] collection
+    #[inline]
+    pub fn entities(&self) -> &Entities {
+        &self.entities
+    }
+
+    /// Retrieves this world's [`Components`] collection
+    #[inline]
+    pub fn components(&self) -> &Components {
+        &self.components
+    }
+
+    /// Sets the number of registered components and resources above which a warning is logged,
+    /// or `None` to never warn
+    ///
+    /// See [`ComponentIds::set_soft_limit`]
+    pub fn set_component_soft_limit(&mut self, limit: Option<usize>) {
+        self.component_ids.set_soft_limit(limit);
+    }
+
     /// Prepares a [`ComponentRegistrator`] for the world
     #[inline]
     pub fn components_registrator(&mut self) -> ComponentsRegistrator {
@@ -293,6 +389,20 @@ impl World {
         component_id
     }
 
+    /// Inserts a new resource with the given `value`
+    ///
+    /// Resources are "unique" data of a given type. If you insert a resource of a type
+    /// that already exists, you will overwrite any existing data
+    #[inline]
+    #[track_caller]
+    pub fn insert_resource<R: Resource>(&mut self, value: R) {
+        let caller = MaybeLocation::caller();
+        let component_id = self.components_registrator().register_resource::<R>();
+        OwningPtr::make(value, |ptr| unsafe {
+            self.insert_resource_by_id(component_id, ptr, caller);
+        });
+    }
+
     /// Gets a mutable reference to the resource of type `T` if it exists,
     /// otherwise initializes the resource by calling its [`FromWorld`] implementation
     #[track_caller]
@@ -395,6 +505,48 @@ impl World {
         unsafe { self.as_unsafe_world_cell().get_resource_mut() }
     }
 
+    /// Iterates over all resources in the world
+    ///
+    /// The returned iterator provides lifetimed, but type-unsafe pointers. Actually reading the contents
+    /// of each resource will require the use of unsafe code
+    #[inline]
+    pub fn iter_resources(&self) -> impl Iterator<Item = (&ComponentInfo, Ptr<'_>)> {
+        self.storages
+            .resources
+            .iter()
+            .filter_map(|(component_id, data)| {
+                let (ptr, _, _) = data.get_with_ticks()?;
+                let info = unsafe {
+                    self.components
+                        .get_info(component_id)
+                        .debug_checked_unwrap()
+                };
+                Some((info, ptr))
+            })
+    }
+
+    /// Mutably iterates over all resources in the world
+    ///
+    /// The returned iterator provides lifetimed, but type-unsafe pointers. Actually reading from or writing
+    /// to the contents of each resource will require the use of unsafe code
+    #[inline]
+    pub fn iter_resources_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&ComponentInfo, MutUntyped<'_>)> {
+        let last_change_tick = self.last_change_tick();
+        let change_tick = self.change_tick();
+        let components = &self.components;
+
+        self.storages
+            .resources
+            .iter_mut()
+            .filter_map(move |(component_id, data)| {
+                let value = data.get_mut(last_change_tick, change_tick)?;
+                let info = unsafe { components.get_info(component_id).debug_checked_unwrap() };
+                Some((info, value))
+            })
+    }
+
     /// Temporarily removes the requested resource from this [`World`], runs custom user code,
     /// then re-adds the resource before returning
     ///
@@ -405,7 +557,11 @@ impl World {
             .unwrap_or_else(|| panic!("resource does not exist: {}", DebugName::type_name::<R>()))
     }
 
-    fn try_resource_scope<R: Resource, U>(
+    /// Temporarily removes the requested resource from this [`World`] if it exists, runs custom user code,
+    /// then re-adds the resource before returning
+    ///
+    /// Returns `None` if the resource does not exist
+    pub fn try_resource_scope<R: Resource, U>(
         &mut self,
         f: impl FnOnce(&mut World, Mut<R>) -> U,
     ) -> Option<U> {
@@ -429,6 +585,7 @@ impl World {
                 this_run: change_tick,
             },
             changed_by: caller.as_mut(),
+            access: AccessGuard::default(),
         };
 
         let result = f(self, value_mut);
@@ -448,6 +605,105 @@ impl World {
         Some(result)
     }
 
+    /// Temporarily removes the resource with the given [`ComponentId`] from this [`World`],
+    /// runs custom user code, then re-adds the resource before returning
+    ///
+    /// This is the untyped counterpart of [`World::resource_scope`], for resources
+    /// whose type is not known at compile time
+    #[track_caller]
+    pub fn resource_scope_by_id<U>(
+        &mut self,
+        component_id: ComponentId,
+        f: impl FnOnce(&mut World, MutUntyped<'_>) -> U,
+    ) -> U {
+        self.try_resource_scope_by_id(component_id, f)
+            .unwrap_or_else(|| panic!("resource does not exist: {component_id:?}"))
+    }
+
+    /// Temporarily removes the resource with the given [`ComponentId`] from this [`World`] if it exists,
+    /// runs custom user code, then re-adds the resource before returning
+    ///
+    /// Returns `None` if the resource does not exist.
+    /// If `f` panics, the resource is leaked instead of being dropped
+    pub fn try_resource_scope_by_id<U>(
+        &mut self,
+        component_id: ComponentId,
+        f: impl FnOnce(&mut World, MutUntyped<'_>) -> U,
+    ) -> Option<U> {
+        let last_change_tick = self.last_change_tick();
+        let change_tick = self.change_tick();
+
+        let layout = self.components.get_info(component_id)?.layout();
+        let (ptr, mut ticks, mut caller) = self
+            .storages
+            .resources
+            .get_mut(component_id)
+            .and_then(ResourceData::remove)?;
+        // Move the value into a scratch buffer to avoid potential mut aliasing
+        let buffer = ScopeBuffer::new(layout);
+        unsafe { ptr::copy_nonoverlapping(ptr.as_ptr(), buffer.ptr.as_ptr(), layout.size()) };
+        let value_mut = MutUntyped {
+            value: unsafe { PtrMut::new(buffer.ptr) },
+            ticks: TicksMut {
+                added: &mut ticks.added,
+                changed: &mut ticks.changed,
+                last_run: last_change_tick,
+                this_run: change_tick,
+            },
+            changed_by: caller.as_mut(),
+            access: AccessGuard::default(),
+        };
+
+        let result = f(self, value_mut);
+        assert!(
+            !self
+                .storages
+                .resources
+                .get(component_id)
+                .is_some_and(ResourceData::is_present),
+            "Resource `{:?}` was inserted during a call to World::resource_scope_by_id.\n\
+        This is not allowed as the original resource is reinserted to the world after the closure is invoked.",
+            component_id
+        );
+
+        let info = self.storages.resources.get_mut(component_id)?;
+        unsafe { info.insert_with_ticks(OwningPtr::new(buffer.ptr), ticks, caller) };
+
+        Some(result)
+    }
+
+    /// Runs a system a single time, without registering it in the world
+    ///
+    /// The system is initialized, run, and its deferred parameters applied,
+    /// so any per-system state such as [`Local`]s is discarded afterwards
+    ///
+    /// [`Local`]: crate::system::Local
+    pub fn run_system_once<O, M>(
+        &mut self,
+        system: impl IntoSystem<(), O, M>,
+    ) -> Result<O, RunSystemError> {
+        self.run_system_once_with(system, ())
+    }
+
+    /// Runs a system taking an [`In`], [`InRef`] or [`InMut`] input a single time,
+    /// without registering it in the world
+    ///
+    /// [`In`]: crate::system::In
+    /// [`InRef`]: crate::system::InRef
+    /// [`InMut`]: crate::system::InMut
+    pub fn run_system_once_with<I, O, M>(
+        &mut self,
+        system: impl IntoSystem<I, O, M>,
+        input: I::Inner<'_>,
+    ) -> Result<O, RunSystemError>
+    where
+        I: SystemInput + 'static,
+    {
+        let mut system = IntoSystem::into_system(system);
+        system.initialize(self);
+        system.run(input, self)
+    }
+
     /// Runs the [`Schedule`] associated with the `label` a single time
     ///
     /// The [`Schedule`] is fetched from the [`Schedules`] resource of the world by its label,
@@ -465,6 +721,20 @@ impl World {
         self.try_schedule_scope(label, |world, sched| sched.run(world))
     }
 
+    /// Builds and initializes every [`Schedule`] stored in the world ahead of its first run
+    ///
+    /// See [`Schedule::initialize_in_place`]. Returns the first build error encountered
+    pub fn initialize_schedules(&mut self) -> Result<(), ScheduleBuildError> {
+        let labels: Vec<_> = self
+            .get_resource::<Schedules>()
+            .map(|schedules| schedules.labels().collect())
+            .unwrap_or_default();
+        for label in labels {
+            self.schedule_scope(label, |world, schedule| schedule.initialize_in_place(world))?;
+        }
+        Ok(())
+    }
+
     /// Temporarily removes the schedule associated with `label` from the world,
     /// runs user code, and finally re-adds the schedule
     ///
@@ -560,6 +830,25 @@ impl World {
         self.flush_commands();
     }
 
+    /// Ensures at least `additional` entities can be spawned without reallocating the entity table
+    ///
+    /// Queued entities are flushed first. Use this before large loads to avoid repeated growth
+    #[track_caller]
+    pub fn reserve_entities(&mut self, additional: u32) {
+        self.flush();
+        self.entities.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the entity table and of every storage as much as possible
+    ///
+    /// Long-running apps can call this after despawning a large scene to return its memory
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        self.flush();
+        self.entities.shrink_to_fit();
+        self.storages.shrink_to_fit();
+    }
+
     /// Clears the internal component tracker state
     ///
     /// The world maintains some internal state about changed and removed components.
@@ -603,12 +892,13 @@ impl World {
     }
 
     /// Iterates all component change ticks and clamps any older than [`MAX_CHANGE_AGE`]
-    /// This also triggers [`CheckChangeTicks`] observers and returns the same event here
+    /// Returns the [`CheckChangeTicks`] event if the ticks were checked. Observers of this event
+    /// are not triggered until observers are ported
     ///
     /// Calling this method prevents [`Tick`]s overflowing and thus prevents false positives when comparing them
     pub fn check_change_ticks(&mut self) -> Option<CheckChangeTicks> {
         let change_tick = self.change_tick();
-        if change_tick.relative_to(self.last_change_tick).get() < CHECK_TICK_THRESHOLD {
+        if change_tick.relative_to(self.last_check_tick).get() < CHECK_TICK_THRESHOLD {
             return None;
         }
 
@@ -631,7 +921,7 @@ impl World {
             schedules.check_change_ticks(check);
         }
 
-        self.trigger(check);
+        // `World::trigger` needs observers, which are not ported yet
         self.flush();
 
         self.last_check_tick = change_tick;
@@ -702,3 +992,30 @@ impl<T: Default> FromWorld for T {
         T::default()
     }
 }
+
+/// Heap allocation holding a resource's bytes while it is scoped out of the [`World`]
+///
+/// Only the allocation is freed on drop, the value inside is never dropped
+struct ScopeBuffer {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+impl ScopeBuffer {
+    fn new(layout: Layout) -> Self {
+        let ptr = if layout.size() == 0 {
+            dangling_with_align(NonZeroUsize::new(layout.align()).unwrap())
+        } else {
+            NonNull::new(unsafe { alloc(layout) }).unwrap_or_else(|| handle_alloc_error(layout))
+        };
+        Self { ptr, layout }
+    }
+}
+
+impl Drop for ScopeBuffer {
+    fn drop(&mut self) {
+        if self.layout.size() != 0 {
+            unsafe { dealloc(self.ptr.as_ptr(), self.layout) };
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    resource::Resource,
+    world::{World, WorldId},
+};
+use core::fmt::{self, Display, Formatter};
+use feap_utils::format::{format_bytes, format_count};
+
+/// A snapshot of the size of a [`World`]
+///
+/// The resource is not updated by the world itself: call [`WorldStats::update`] once per frame
+/// from a system, or add the `WorldStatsPlugin` of `feap_app` which does so in the `Last` schedule.
+/// Diagnostics and tests can then read it like any other resource.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WorldStats {
+    /// The number of entities alive
+    pub entities: usize,
+    /// The number of entities the entity table can hold without reallocating
+    pub entity_capacity: usize,
+    /// The number of component and resource types registered
+    pub component_types: usize,
+    /// The size in bytes of the commands queued in the world and not applied yet
+    pub queued_command_bytes: usize,
+    /// The number of resources the resource storage can hold without reallocating
+    pub resource_capacity: usize,
+    /// The number of worlds alive in the process, including this one
+    pub live_worlds: usize,
+}
+
+impl WorldStats {
+    /// Takes a snapshot of `world`
+    pub fn from_world(world: &World) -> Self {
+        Self {
+            entities: world.entities.len() as usize,
+            entity_capacity: world.entities.capacity(),
+            component_types: world.components.len(),
+            // SAFETY: the queue is only read, and `&World` guarantees it is not being applied
+            queued_command_bytes: unsafe { world.command_queue.len() },
+            resource_capacity: world.storages.resources.capacity(),
+            live_worlds: WorldId::live_worlds(),
+        }
+    }
+
+    /// Refreshes the stats stored in `world`, inserting the resource if it does not exist
+    pub fn update(world: &mut World) {
+        let stats = Self::from_world(world);
+        match world.get_resource_mut::<Self>() {
+            // Only go through `DerefMut` when something changed, to keep change detection accurate
+            Some(mut current) => {
+                if *current != stats {
+                    *current = stats;
+                }
+            }
+            None => {
+                world.insert_resource(stats);
+            }
+        }
+    }
+}
+
+/// Displays the stats on a single line, such as
+/// `1,024 entities (capacity 2,048), 12 component types, 2.00 KiB of queued commands,
+/// 1 live worlds`
+impl Display for WorldStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} entities (capacity {}), {} component types, {} of queued commands, {} live worlds",
+            format_count(self.entities as u64),
+            format_count(self.entity_capacity as u64),
+            format_count(self.component_types as u64),
+            format_bytes(self.queued_command_bytes as u64),
+            format_count(self.live_worlds as u64),
+        )
+    }
+}
@@ -0,0 +1,362 @@
+//! Tests for [`World`], such as the debug borrow checks of
+//! [`UnsafeWorldCell`](super::UnsafeWorldCell)
+
+use super::{World, WorldId, WorldStats};
+use crate::{
+    component::{Tick, CHECK_TICK_THRESHOLD},
+    resource::Resource,
+    schedule::{IntoScheduleConfigs, ScheduleLabel, Schedules},
+};
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+#[derive(Resource, Default)]
+struct A(u32);
+
+#[derive(Resource, Default)]
+struct B(u32);
+
+#[test]
+fn sequential_borrows_do_not_conflict() {
+    let mut world = World::new();
+    world.insert_resource(A(0));
+    let cell = world.as_unsafe_world_cell();
+    unsafe {
+        cell.get_resource_mut::<A>().unwrap().0 = 1;
+        assert_eq!(cell.get_resource::<A>().unwrap().0, 1);
+        cell.get_resource_mut::<A>().unwrap().0 = 2;
+        assert_eq!(cell.get_resource::<A>().unwrap().0, 2);
+    }
+}
+
+#[test]
+fn exclusive_borrows_of_different_resources_do_not_conflict() {
+    let mut world = World::new();
+    world.insert_resource(A(0));
+    world.insert_resource(B(0));
+    let cell = world.as_unsafe_world_cell();
+    unsafe {
+        let mut a = cell.get_resource_mut::<A>().unwrap();
+        let mut b = cell.get_resource_mut::<B>().unwrap();
+        a.0 = 1;
+        b.0 = 2;
+    }
+}
+
+#[test]
+fn borrows_end_with_a_new_cell() {
+    let mut world = World::new();
+    world.insert_resource(A(0));
+    // The guard of this borrow is only dropped at the end of the test
+    let _a = unsafe { world.as_unsafe_world_cell().get_resource_mut::<A>() };
+    let cell = world.as_unsafe_world_cell();
+    unsafe { cell.get_resource_mut::<A>().unwrap().0 = 1 };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "exclusive borrow of")]
+fn aliased_exclusive_borrows_panic() {
+    let mut world = World::new();
+    world.insert_resource(A(0));
+    let cell = world.as_unsafe_world_cell();
+    unsafe {
+        let _first = cell.get_resource_mut::<A>();
+        let _second = cell.get_resource_mut::<A>();
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "shared borrow of")]
+fn shared_borrow_during_exclusive_borrow_panics() {
+    let mut world = World::new();
+    world.insert_resource(A(0));
+    let cell = world.as_unsafe_world_cell();
+    unsafe {
+        let _exclusive = cell.get_resource_mut::<A>();
+        let _shared = cell.get_resource::<A>();
+    }
+}
+
+#[test]
+#[cfg(all(debug_assertions, not(feature = "debug")))]
+#[should_panic(expected = "borrow of `ComponentId(")]
+fn conflicts_name_the_component_id_without_debug_names() {
+    let mut world = World::new();
+    world.insert_resource(A(0));
+    let cell = world.as_unsafe_world_cell();
+    unsafe {
+        let _first = cell.get_resource_mut::<A>();
+        let _second = cell.get_resource_mut::<A>();
+    }
+}
+
+#[test]
+fn live_worlds_soft_limit() {
+    let _world = World::new();
+    WorldId::set_soft_limit(Some(0));
+    assert!(WorldId::exceeds_soft_limit());
+    WorldId::set_soft_limit(None);
+    assert!(!WorldId::exceeds_soft_limit());
+}
+
+#[test]
+fn component_soft_limit() {
+    let mut world = World::new();
+    world.set_component_soft_limit(Some(1));
+    world.init_resource::<A>();
+    assert!(!world.component_ids.exceeds_soft_limit());
+    world.init_resource::<B>();
+    assert!(world.component_ids.exceeds_soft_limit());
+    // Registrations keep working past the soft limit
+    assert!(world.get_resource::<B>().is_some());
+
+    world.set_component_soft_limit(None);
+    assert!(!world.component_ids.exceeds_soft_limit());
+}
+
+#[derive(Resource, Default)]
+struct Unit;
+
+#[test]
+fn resource_scope_by_id_keeps_mutations() {
+    let mut world = World::new();
+    let id = world.init_resource::<A>();
+    world.insert_resource(B(1));
+    let result = world.resource_scope_by_id(id, |world, value| {
+        assert!(!world.contains_resource::<A>());
+        let mut a = unsafe { value.with_type::<A>() };
+        a.0 = world.get_resource::<B>().unwrap().0 + 1;
+        a.0
+    });
+    assert_eq!(result, 2);
+    assert_eq!(world.get_resource::<A>().unwrap().0, 2);
+}
+
+#[test]
+fn resource_scope_by_id_supports_zero_sized_resources() {
+    let mut world = World::new();
+    let id = world.init_resource::<Unit>();
+    let ran = world.resource_scope_by_id(id, |world, value| {
+        let _unit = unsafe { value.with_type::<Unit>() };
+        !world.contains_resource::<Unit>()
+    });
+    assert!(ran);
+    assert!(world.contains_resource::<Unit>());
+}
+
+#[test]
+fn resource_scope_by_id_returns_none_for_missing_resources() {
+    let id = World::new().init_resource::<A>();
+    let mut world = World::new();
+    assert!(world.try_resource_scope_by_id(id, |_, _| ()).is_none());
+}
+
+#[test]
+#[should_panic(expected = "was inserted during a call to World::resource_scope_by_id")]
+fn resource_scope_by_id_panics_on_reinsertion() {
+    let mut world = World::new();
+    let id = world.init_resource::<A>();
+    world.resource_scope_by_id(id, |world, _| world.insert_resource(A(1)));
+}
+
+#[test]
+fn change_ticks_are_checked_relative_to_the_last_check() {
+    let mut world = World::new();
+    assert!(world.check_change_ticks().is_none());
+
+    // As if ticks were checked recently, while no frame ended for a long time
+    *world.change_tick.get_mut() = CHECK_TICK_THRESHOLD + 10;
+    world.last_check_tick = Tick::new(CHECK_TICK_THRESHOLD);
+    assert!(world.check_change_ticks().is_none());
+}
+
+#[test]
+fn change_ticks_are_checked_once_per_threshold() {
+    let mut world = World::new();
+    world.insert_resource(A(0));
+    *world.change_tick.get_mut() = CHECK_TICK_THRESHOLD + 10;
+
+    let check = world.check_change_ticks().unwrap();
+    assert_eq!(check.present_tick(), Tick::new(CHECK_TICK_THRESHOLD + 10));
+    assert_eq!(world.last_check_tick, check.present_tick());
+    assert!(world.check_change_ticks().is_none());
+}
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct Stored;
+
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct Running;
+
+fn count(world: &mut World) {
+    world.get_resource_mut::<A>().unwrap().0 += 1;
+}
+
+#[test]
+fn running_a_schedule_checks_the_ticks_of_stored_systems() {
+    let mut world = World::new();
+    world.insert_resource(A(0));
+    let mut schedules = Schedules::default();
+    schedules.add_systems(Stored, (count, count.distinct()).chain());
+    schedules.add_systems(Running, count);
+    world.insert_resource(schedules);
+    // Builds the stored schedule, so its systems hold ticks
+    world.run_schedule(Stored);
+
+    *world.change_tick.get_mut() = CHECK_TICK_THRESHOLD + 10;
+    world.run_schedule(Running);
+    assert_eq!(world.last_check_tick, Tick::new(CHECK_TICK_THRESHOLD + 10));
+    world.run_schedule(Stored);
+    assert_eq!(world.get_resource::<A>().unwrap().0, 5);
+}
+
+#[test]
+fn world_stats_count_entities() {
+    let mut world = World::new();
+    WorldStats::update(&mut world);
+    assert_eq!(world.get_resource::<WorldStats>().unwrap().entities, 0);
+
+    let first = world.entities.alloc();
+    world.entities.alloc();
+    WorldStats::update(&mut world);
+    let stats = *world.get_resource::<WorldStats>().unwrap();
+    assert_eq!(stats.entities, 2);
+    assert!(stats.entity_capacity >= 2);
+    assert_eq!(stats.component_types, world.components.len());
+
+    world.entities.free(first);
+    WorldStats::update(&mut world);
+    assert_eq!(world.get_resource::<WorldStats>().unwrap().entities, 1);
+}
+
+/// Returns the tick at which the resource `R` was last changed
+fn changed_tick<R: Resource>(world: &World) -> Tick {
+    let id = world.components.get_valid_resource_id(TypeId::of::<R>()).unwrap();
+    let (_, ticks, _) = world.storages.resources.get(id).unwrap().get_with_ticks().unwrap();
+    unsafe { *ticks.changed.get() }
+}
+
+#[test]
+fn world_stats_are_only_changed_when_they_differ() {
+    let mut world = World::new();
+    WorldStats::update(&mut world);
+    let before = *world.get_resource::<WorldStats>().unwrap();
+    let changed = changed_tick::<WorldStats>(&world);
+
+    world.increment_change_tick();
+    WorldStats::update(&mut world);
+    // Worlds created by other tests change `live_worlds`, so compare against what was stored
+    if *world.get_resource::<WorldStats>().unwrap() == before {
+        assert_eq!(changed_tick::<WorldStats>(&world), changed);
+    } else {
+        assert_ne!(changed_tick::<WorldStats>(&world), changed);
+    }
+
+    world.entities.alloc();
+    world.increment_change_tick();
+    WorldStats::update(&mut world);
+    let this_run = world.change_tick();
+    assert_eq!(changed_tick::<WorldStats>(&world), this_run);
+}
+
+#[test]
+fn reserved_entity_capacity_is_released_by_shrinking() {
+    let mut world = World::new();
+    world.reserve_entities(128);
+    assert!(world.entities.capacity() >= 128);
+
+    let live = world.entities.alloc();
+    let stale = world.entities.alloc();
+    world.entities.free(stale);
+    world.shrink_to_fit();
+    assert!(world.entities.capacity() < 128);
+    assert!(world.entities.capacity() >= 2);
+
+    // Freed rows survive shrinking, so their old generation is still rejected
+    assert!(world.entities.contains(live));
+    assert!(!world.entities.contains(stale));
+    let reused = world.entities.alloc();
+    assert_eq!(reused.index(), stale.index());
+    assert!(world.entities.contains(reused));
+    assert!(!world.entities.contains(stale));
+}
+
+#[test]
+fn shrinking_releases_resource_capacity() {
+    let mut world = World::new();
+    world.insert_resource(A(1));
+    world.insert_resource(B(2));
+    world.insert_resource(Unit);
+    let before = world.storages.resources.capacity();
+    assert!(before > 3);
+
+    world.shrink_to_fit();
+    assert!(world.storages.resources.capacity() < before);
+    assert_eq!(world.get_resource::<B>().unwrap().0, 2);
+}
+
+#[test]
+fn resources_can_be_iterated_and_mutated() {
+    let mut world = World::new();
+    world.insert_resource(A(1));
+    world.insert_resource(B(2));
+
+    let mut values: Vec<_> = world
+        .iter_resources()
+        .map(|(info, ptr)| match info.type_id() {
+            Some(id) if id == TypeId::of::<A>() => ("A", unsafe { ptr.deref::<A>() }.0),
+            Some(id) if id == TypeId::of::<B>() => ("B", unsafe { ptr.deref::<B>() }.0),
+            _ => panic!("unexpected resource {}", info.name()),
+        })
+        .collect();
+    values.sort();
+    assert_eq!(values, [("A", 1), ("B", 2)]);
+
+    world.increment_change_tick();
+    let this_run = world.change_tick();
+    for (info, value) in world.iter_resources_mut() {
+        if info.type_id() == Some(TypeId::of::<A>()) {
+            unsafe { value.with_type::<A>() }.0 += 10;
+        }
+    }
+    assert_eq!(world.get_resource::<A>().unwrap().0, 11);
+    assert_eq!(world.get_resource::<B>().unwrap().0, 2);
+    // Only resources written through `MutUntyped` are flagged as changed
+    assert_eq!(changed_tick::<A>(&world), this_run);
+    assert_ne!(changed_tick::<B>(&world), this_run);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn world_views_can_be_shared_across_threads() {
+    use super::WorldView;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<WorldView<'static>>();
+    assert_send_sync::<crate::component::Components>();
+
+    let mut world = World::new();
+    let a = world.init_resource::<A>();
+    world.insert_resource(B(2));
+    world.resource_mut::<A>().0 = 1;
+    let view = world.as_view();
+    let (id, tick) = (world.id(), world.read_change_tick());
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(move || {
+                assert_eq!(view.id(), id);
+                assert_eq!(view.read_change_tick(), tick);
+                assert!(view.components().len() >= 2);
+                assert!(view.contains_resource::<A>());
+                assert!(!view.contains_resource::<Unit>());
+                assert_eq!(view.resource::<A>().0, 1);
+                assert_eq!(view.get_resource::<B>().map(|b| b.0), Some(2));
+                assert!(view.get_resource_by_id(a).is_some());
+                assert_eq!(view.iter_resources().count(), 2);
+            });
+        }
+    });
+}
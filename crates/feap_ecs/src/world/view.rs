@@ -0,0 +1,109 @@
+use crate::{
+    component::{ComponentId, ComponentInfo, Components, Tick},
+    resource::Resource,
+    world::{World, WorldId},
+};
+use feap_core::ptr::Ptr;
+use feap_utils::debug_info::DebugName;
+
+/// A read-only view of a [`World`] that can be shared across threads
+///
+/// Unlike `&World`, this type is always [`Send`] and [`Sync`]: it can only reach data that is
+/// itself `Send + Sync`, and only ever hands out shared references to it. This allows analysis
+/// or export passes to run concurrently on a world without going through a schedule executor.
+#[derive(Copy, Clone)]
+pub struct WorldView<'w> {
+    world: &'w World,
+}
+
+// SAFETY: a `WorldView` borrows the world immutably for `'w`, so nothing can write to it while
+// the view exists: every `UnsafeCell` reachable from here (resource ticks and callers) is only
+// written through `&mut World`. The methods below only read data that is `Sync`, or resources
+// stored in `Resources<true>`, which are all `Send + Sync`; each one carries its own justification.
+// The command queue and non-send resources are never reachable through a view.
+unsafe impl Send for WorldView<'_> {}
+unsafe impl Sync for WorldView<'_> {}
+
+impl<'w> WorldView<'w> {
+    /// Creates a new read-only view of `world`
+    #[inline]
+    pub fn new(world: &'w World) -> Self {
+        Self { world }
+    }
+
+    /// Retrieves the unique ID of the viewed [`World`]
+    #[inline]
+    pub fn id(self) -> WorldId {
+        // SAFETY: `WorldId` is plain `Copy` data
+        self.world.id()
+    }
+
+    /// Retrieves the viewed world's [`Components`] collection
+    #[inline]
+    pub fn components(self) -> &'w Components {
+        // SAFETY: `Components` is `Sync`, and registration requires `&mut World`
+        &self.world.components
+    }
+
+    /// Reads the current change tick of the viewed world
+    #[inline]
+    pub fn read_change_tick(self) -> Tick {
+        // SAFETY: the change tick is an atomic and is only loaded here
+        self.world.read_change_tick()
+    }
+
+    /// Returns `true` if a resource of type `R` exists
+    #[inline]
+    pub fn contains_resource<R: Resource>(self) -> bool {
+        // SAFETY: only reads whether the `Resources<true>` entry holds a value
+        self.world.contains_resource::<R>()
+    }
+
+    /// Gets a reference to the resource of the given type if it exists
+    #[inline]
+    pub fn get_resource<R: Resource>(self) -> Option<&'w R> {
+        // SAFETY: `R: Resource` is `Sync`, and read-only access is not recorded in the access log
+        self.world.get_resource::<R>()
+    }
+
+    /// Gets a reference to the resource of the given type
+    /// Panics if the resource does not exist
+    #[inline]
+    #[track_caller]
+    pub fn resource<R: Resource>(self) -> &'w R {
+        // SAFETY: defers to `get_resource`
+        match self.get_resource::<R>() {
+            Some(x) => x,
+            None => panic!(
+                "Requested resource {} does not exist in the `World`.",
+                DebugName::type_name::<R>()
+            ),
+        }
+    }
+
+    /// Gets a pointer to the resource with the id [`ComponentId`] if it exists
+    #[inline]
+    pub fn get_resource_by_id(self, component_id: ComponentId) -> Option<Ptr<'w>> {
+        // SAFETY: `Resources<true>` only holds `Send + Sync` values, and the ticks are dropped
+        // without being read
+        self.world
+            .storages
+            .resources
+            .get(component_id)?
+            .get_with_ticks()
+            .map(|(ptr, _, _)| ptr)
+    }
+
+    /// Iterates over all resources in the viewed world
+    #[inline]
+    pub fn iter_resources(self) -> impl Iterator<Item = (&'w ComponentInfo, Ptr<'w>)> {
+        // SAFETY: like `get_resource_by_id` for every resource, with `ComponentInfo` being `Sync`
+        self.world.iter_resources()
+    }
+}
+
+impl<'w> From<&'w World> for WorldView<'w> {
+    fn from(world: &'w World) -> Self {
+        WorldView::new(world)
+    }
+}
@@ -1,7 +1,9 @@
 use crate::cfg;
 cfg::alloc! {
-    use alloc::{fmt};
+    use alloc::{fmt, string::String};
 }
+#[cfg(feature = "debug")]
+use {alloc::borrow::Cow, core::any::type_name};
 
 #[cfg(not(feature = "debug"))]
 const FEATURE_DISABLED: &str = "Enable the debug feature to see the name";
@@ -36,6 +38,23 @@ impl DebugName {
         }
     }
 
+    /// Creates a new `DebugName` from an owned string
+    ///
+    /// The string is dropped if the `debug` feature is disabled
+    #[cfg_attr(
+        not(feature = "debug"),
+        expect(
+            unused_variables,
+            reason = "The value will be ignored if the `debug` feature is not enabled"
+        )
+    )]
+    pub fn owned(value: String) -> Self {
+        DebugName {
+            #[cfg(feature = "debug")]
+            name: Cow::Owned(value),
+        }
+    }
+
     // /// Get the [`ShortName`] corresponding to this debug name
     // pub fn shortname(&self) -> ShortName<'_> {
     //     todo!()
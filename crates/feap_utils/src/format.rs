@@ -0,0 +1,103 @@
+//! Human-readable formatting of durations, byte sizes and counts
+//!
+//! Diagnostics across crates go through these helpers, so the same quantity is always
+//! presented the same way. The returned values implement [`Display`] and do not allocate
+
+use core::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Formats `duration` with the largest fitting unit, such as `850 ns`, `12.30 ms` or `2 min 03 s`
+pub fn format_duration(duration: Duration) -> FormattedDuration {
+    FormattedDuration(duration)
+}
+
+/// Formats `bytes` with binary units, such as `512 B` or `1.50 MiB`
+pub fn format_bytes(bytes: u64) -> FormattedBytes {
+    FormattedBytes(bytes)
+}
+
+/// Formats `count` with thousands separators, such as `1,234,567`
+pub fn format_count(count: u64) -> FormattedCount {
+    FormattedCount(count)
+}
+
+/// A [`Duration`] displayed by [`format_duration`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormattedDuration(pub Duration);
+
+impl Display for FormattedDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Units are picked by the rounded value, so `999_999 ns` reads `1.00 ms`, not `1000.00 µs`
+        let nanos = self.0.as_nanos();
+        if nanos < 1_000 {
+            return write!(f, "{nanos} ns");
+        }
+        let micros = round_hundredths(nanos as f64 / 1e3);
+        if micros < 1e3 {
+            return write!(f, "{micros:.2} µs");
+        }
+        let millis = round_hundredths(nanos as f64 / 1e6);
+        if millis < 1e3 {
+            return write!(f, "{millis:.2} ms");
+        }
+        let secs = round_hundredths(self.0.as_secs_f64());
+        if secs < 60.0 {
+            return write!(f, "{secs:.2} s");
+        }
+        let secs = (self.0 + Duration::from_millis(500)).as_secs();
+        if secs < 3600 {
+            write!(f, "{} min {:02} s", secs / 60, secs % 60)
+        } else {
+            write!(f, "{} h {:02} min", secs / 3600, secs % 3600 / 60)
+        }
+    }
+}
+
+/// A byte size displayed by [`format_bytes`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormattedBytes(pub u64);
+
+impl Display for FormattedBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while round_hundredths(value) >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.2} {}", round_hundredths(value), UNITS[unit])
+    }
+}
+
+/// Rounds `value` to two decimals, the precision the helpers print with
+fn round_hundredths(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// A count displayed by [`format_count`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormattedCount(pub u64);
+
+impl Display for FormattedCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fn write_grouped(f: &mut Formatter<'_>, count: u64) -> fmt::Result {
+            if count < 1000 {
+                return write!(f, "{count}");
+            }
+            write_grouped(f, count / 1000)?;
+            write!(f, ",{:03}", count % 1000)
+        }
+
+        write_grouped(f, self.0)
+    }
+}
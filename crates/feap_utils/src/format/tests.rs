@@ -0,0 +1,54 @@
+//! Tests for the formatting helpers
+
+use super::{format_bytes, format_count, format_duration};
+use alloc::string::{String, ToString};
+use core::time::Duration;
+
+fn duration(duration: Duration) -> String {
+    format_duration(duration).to_string()
+}
+
+#[test]
+fn durations_use_the_largest_fitting_unit() {
+    assert_eq!(duration(Duration::from_nanos(850)), "850 ns");
+    assert_eq!(duration(Duration::from_nanos(1_500)), "1.50 µs");
+    assert_eq!(duration(Duration::from_micros(12_300)), "12.30 ms");
+    assert_eq!(duration(Duration::from_millis(2_500)), "2.50 s");
+    assert_eq!(duration(Duration::from_secs(123)), "2 min 03 s");
+    assert_eq!(duration(Duration::from_secs(3_723)), "1 h 02 min");
+}
+
+#[test]
+fn durations_pick_the_unit_after_rounding() {
+    assert_eq!(duration(Duration::from_nanos(999)), "999 ns");
+    assert_eq!(duration(Duration::from_nanos(999_994)), "999.99 µs");
+    assert_eq!(duration(Duration::from_nanos(999_999)), "1.00 ms");
+    assert_eq!(duration(Duration::from_nanos(999_999_999)), "1.00 s");
+    assert_eq!(duration(Duration::from_millis(59_999)), "1 min 00 s");
+    assert_eq!(duration(Duration::from_millis(3_599_600)), "1 h 00 min");
+}
+
+#[test]
+fn bytes_use_binary_units() {
+    assert_eq!(format_bytes(0).to_string(), "0 B");
+    assert_eq!(format_bytes(1023).to_string(), "1023 B");
+    assert_eq!(format_bytes(1024).to_string(), "1.00 KiB");
+    assert_eq!(format_bytes(3 * 1024 * 1024 / 2).to_string(), "1.50 MiB");
+    assert_eq!(format_bytes(u64::MAX).to_string(), "16.00 EiB");
+}
+
+#[test]
+fn bytes_pick_the_unit_after_rounding() {
+    assert_eq!(format_bytes(1_048_575).to_string(), "1.00 MiB");
+    assert_eq!(format_bytes(1_048_570).to_string(), "1023.99 KiB");
+    assert_eq!(format_bytes((1 << 30) - 1).to_string(), "1.00 GiB");
+}
+
+#[test]
+fn counts_are_grouped_by_thousands() {
+    assert_eq!(format_count(0).to_string(), "0");
+    assert_eq!(format_count(999).to_string(), "999");
+    assert_eq!(format_count(1_000).to_string(), "1,000");
+    assert_eq!(format_count(1_234_567).to_string(), "1,234,567");
+    assert_eq!(format_count(1_000_005).to_string(), "1,000,005");
+}
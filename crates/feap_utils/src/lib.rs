@@ -2,8 +2,15 @@
 //!
 
 pub mod debug_info;
+pub mod format;
 pub mod map;
 
+#[cfg(feature = "parallel")]
+mod parallel;
+
+#[cfg(feature = "parallel")]
+pub use parallel::Parallel;
+
 cfg::std! {
     extern crate std;
 }
@@ -0,0 +1,136 @@
+use alloc::{sync::Arc, vec::Vec};
+use core::ops::DerefMut;
+use std::{
+    sync::{Mutex, PoisonError, RwLock, TryLockError},
+    thread::{self, ThreadId},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Only [`Parallel::scope`] clones the cells, and the clone is dropped before it returns
+const SCOPE_ENDED: &str = "`Parallel::scope` releases its cell before returning";
+
+/// A cohesive set of thread-local values of a given type
+///
+/// Each thread gets its own cell the first time it calls [`Parallel::scope`], so parallel systems
+/// (or the multi-threaded executor) can collect values without contention, and merge them once
+/// the parallel section is over.
+///
+/// Cells are kept in the order in which threads first accessed them, which is also the order
+/// [`Parallel::drain`] and [`Parallel::iter_mut`] yield them in.
+pub struct Parallel<T: Send> {
+    locals: RwLock<Vec<(ThreadId, Arc<Mutex<T>>)>>,
+}
+
+impl<T: Send> Default for Parallel<T> {
+    fn default() -> Self {
+        Self {
+            locals: RwLock::new(Vec::new()),
+        }
+    }
+}
+
+impl<T: Send> Parallel<T> {
+    /// Gets a mutable iterator over all of the per-thread queues
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &'_ mut T> {
+        self.locals
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter_mut()
+            .map(|(_, local)| {
+                Arc::get_mut(local)
+                    .expect(SCOPE_ENDED)
+                    .get_mut()
+                    .unwrap_or_else(PoisonError::into_inner)
+            })
+    }
+
+    /// Drains all per-thread values, in the order in which threads first accessed them
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.locals
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .drain(..)
+            .map(|(_, local)| {
+                Arc::into_inner(local)
+                    .expect(SCOPE_ENDED)
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner)
+            })
+    }
+
+    /// Clears all of the stored thread local values
+    pub fn clear(&mut self) {
+        self.locals
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl<T: Default + Send> Parallel<T> {
+    /// Retrieves the thread-local value for the current thread and runs `f` on it
+    ///
+    /// If there is no thread-local value, it will be initialized to its default.
+    /// No lock on the set of cells is held while `f` runs, so other threads can register their
+    /// own cells in the meantime
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within `f` on the same thread, as the cell is already borrowed
+    pub fn scope<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let local = self.local(thread::current().id());
+        // Only the current thread ever locks its own cell, so it can only be held by an outer
+        // `scope` call on this thread
+        let mut local = match local.try_lock() {
+            Ok(local) => local,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => {
+                panic!("`Parallel::scope` was called again from within its own closure")
+            }
+        };
+        f(local.deref_mut())
+    }
+
+    /// Returns the cell of the thread `id`, initializing it to its default if there is none
+    fn local(&self, id: ThreadId) -> Arc<Mutex<T>> {
+        let locals = self.locals.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some((_, local)) = locals.iter().find(|(thread, _)| *thread == id) {
+            return local.clone();
+        }
+        drop(locals);
+
+        let mut locals = self.locals.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some((_, local)) = locals.iter().find(|(thread, _)| *thread == id) {
+            return local.clone();
+        }
+        let local = Arc::new(Mutex::new(T::default()));
+        locals.push((id, local.clone()));
+        local
+    }
+}
+
+impl<T, I> Parallel<I>
+where
+    I: IntoIterator<Item = T> + Default + Send + 'static,
+{
+    /// Drains all enqueued items from all threads and returns an iterator over them
+    ///
+    /// Items of a single thread keep their relative order
+    pub fn drain_flatten(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.drain().flat_map(IntoIterator::into_iter)
+    }
+}
+
+impl<T: Send> Parallel<Vec<T>> {
+    /// Collects all enqueued items from all threads and appends them to the end of a
+    /// single Vec
+    pub fn drain_into(&mut self, out: &mut Vec<T>) {
+        let size = self.iter_mut().map(|queue| queue.len()).sum();
+        out.reserve(size);
+        for queue in self.iter_mut() {
+            out.append(queue);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Tests for [`Parallel`]
+
+use super::Parallel;
+use alloc::{vec, vec::Vec};
+use std::{sync::mpsc, thread, time::Duration};
+
+#[test]
+fn scope_reuses_the_cell_of_the_current_thread() {
+    let mut parallel = Parallel::<Vec<u32>>::default();
+    parallel.scope(|local| local.push(1));
+    parallel.scope(|local| local.push(2));
+    assert_eq!(parallel.drain().collect::<Vec<_>>(), vec![vec![1, 2]]);
+    assert_eq!(parallel.drain().count(), 0);
+}
+
+#[test]
+fn each_thread_gets_its_own_cell() {
+    let mut parallel = Parallel::<Vec<u32>>::default();
+    thread::scope(|s| {
+        for i in 0..4 {
+            let parallel = &parallel;
+            s.spawn(move || parallel.scope(|local| local.push(i)));
+        }
+    });
+    assert_eq!(parallel.iter_mut().count(), 4);
+
+    let mut items = Vec::new();
+    parallel.drain_into(&mut items);
+    items.sort_unstable();
+    assert_eq!(items, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn new_thread_registers_while_another_scope_runs() {
+    let parallel = Parallel::<u32>::default();
+    let (started, wait_started) = mpsc::channel();
+    let (registered, wait_registered) = mpsc::channel();
+    let parallel = &parallel;
+    thread::scope(|s| {
+        s.spawn(move || {
+            parallel.scope(|local| {
+                *local += 1;
+                started.send(()).unwrap();
+                // Times out if the other thread cannot register its cell while this scope runs
+                wait_registered
+                    .recv_timeout(Duration::from_secs(10))
+                    .expect("registering a new thread blocked on a running scope");
+            });
+        });
+        s.spawn(move || {
+            wait_started.recv().unwrap();
+            parallel.scope(|local| *local += 1);
+            registered.send(()).unwrap();
+        });
+    });
+}
+
+#[test]
+#[should_panic(expected = "called again from within its own closure")]
+fn reentrant_scope_panics() {
+    let parallel = Parallel::<u32>::default();
+    parallel.scope(|_| parallel.scope(|local| *local += 1));
+}
+
+#[test]
+fn clear_forgets_all_cells() {
+    let mut parallel = Parallel::<u32>::default();
+    parallel.scope(|local| *local = 7);
+    parallel.clear();
+    parallel.scope(|local| assert_eq!(*local, 0));
+}